@@ -27,7 +27,7 @@ impl std::fmt::Display for SkipReason {
     }
 }
 
-/// Parsed macro attributes from `#[struct_to_gts_schema(...)]`
+/// Parsed macro attributes from `#[struct_to_gts_schema(...)]` or `#[gts(...)]`
 #[derive(Debug, Clone)]
 struct MacroAttrs {
     dir_path: String,
@@ -35,6 +35,22 @@ struct MacroAttrs {
     description: Option<String>,
     properties: Option<String>,
     base: BaseAttr,
+    deprecated: Option<String>,
+    /// Parsed `examples` JSON array
+    examples: Option<serde_json::Value>,
+}
+
+/// Per-field metadata parsed from doc comments and `#[gts(...)]` field attributes
+#[derive(Debug, Clone, Default)]
+struct FieldAttrs {
+    /// Joined `///` doc comment lines
+    doc: Option<String>,
+    /// Allowed string values from `#[gts(enum_values = "A,B,C")]`
+    enum_values: Option<Vec<String>>,
+    /// `#[gts(skip)]`: excluded when all fields are included (`properties = "*"`)
+    skip: bool,
+    /// Serialized name from `#[gts(rename = "...")]` or `#[serde(rename = "...")]`
+    rename: Option<String>,
 }
 
 /// Base attribute type
@@ -148,7 +164,7 @@ pub fn generate_schemas_from_rust(
 
     if schemas_generated == 0 {
         println!(
-            "\n- No schemas found. Make sure your structs are annotated with `#[struct_to_gts_schema(...)]`"
+            "\n- No schemas found. Make sure your structs are annotated with `#[struct_to_gts_schema(...)]` or `#[derive(GtsSchema)]`"
         );
     }
 
@@ -217,13 +233,16 @@ fn has_ignore_directive(content: &str) -> bool {
     false
 }
 
-/// Parse the attribute body of `#[struct_to_gts_schema(...)]` to extract individual attributes
+/// Parse the attribute body of `#[struct_to_gts_schema(...)]` or `#[gts(...)]` to extract individual attributes
 fn parse_macro_attrs(attr_body: &str) -> Option<MacroAttrs> {
     // Patterns for extracting individual attributes
     let dir_path_re = Regex::new(r#"dir_path\s*=\s*"([^"]+)""#).ok()?;
     let schema_id_re = Regex::new(r#"schema_id\s*=\s*"([^"]+)""#).ok()?;
     let description_re = Regex::new(r#"description\s*=\s*"([^"]+)""#).ok()?;
     let properties_re = Regex::new(r#"properties\s*=\s*"([^"]+)""#).ok()?;
+    let deprecated_re = Regex::new(r#"\bdeprecated\s*=\s*"([^"]*)""#).ok()?;
+    let examples_raw_re = Regex::new(r##"(?s)\bexamples\s*=\s*r#"(.*?)"#"##).ok()?;
+    let examples_re = Regex::new(r#"(?s)\bexamples\s*=\s*"((?:[^"\\]|\\.)*)""#).ok()?;
     let base_true_re = Regex::new(r"\bbase\s*=\s*true\b").ok()?;
     let base_parent_re = Regex::new(r"\bbase\s*=\s*([A-Z]\w*)").ok()?;
 
@@ -242,6 +261,21 @@ fn parse_macro_attrs(attr_body: &str) -> Option<MacroAttrs> {
     let properties = properties_re
         .captures(attr_body)
         .and_then(|c| c.get(1).map(|m| m.as_str().to_owned()));
+    let deprecated = deprecated_re
+        .captures(attr_body)
+        .and_then(|c| c.get(1).map(|m| m.as_str().to_owned()));
+    // examples may be a raw string (r#"..."#) or a regular string with escaped quotes;
+    // the macro rejects anything that is not a JSON array, so skip those here
+    let examples = examples_raw_re
+        .captures(attr_body)
+        .and_then(|c| c.get(1).map(|m| m.as_str().to_owned()))
+        .or_else(|| {
+            examples_re
+                .captures(attr_body)
+                .and_then(|c| c.get(1).map(|m| unescape_str_literal(m.as_str())))
+        })
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .filter(serde_json::Value::is_array);
 
     // Parse base attribute
     let base = if base_true_re.is_match(attr_body) {
@@ -259,9 +293,30 @@ fn parse_macro_attrs(attr_body: &str) -> Option<MacroAttrs> {
         description,
         properties,
         base,
+        deprecated,
+        examples,
     })
 }
 
+/// Undo the escaping of a regular (non-raw) Rust string literal body
+fn unescape_str_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
 /// Extract schema metadata from Rust source and generate JSON files
 /// Returns a vector of (`schema_id`, `file_path`) tuples for each generated schema
 fn extract_and_generate_schemas(
@@ -270,14 +325,18 @@ fn extract_and_generate_schemas(
     source_root: &Path,
     source_file: &Path,
 ) -> Result<Vec<(String, String)>> {
-    // Match #[struct_to_gts_schema(...)] followed by struct definition
+    // Match #[struct_to_gts_schema(...)] or the #[gts(...)] container attribute of
+    // #[derive(GtsSchema)] followed by struct definition
     // Captures: (1) attribute body, (2) struct name, (3) optional generics, (4) struct body or semicolon for unit structs
     let re = Regex::new(
-        r"(?s)#\[struct_to_gts_schema\(([^)]+)\)\]\s*(?:#\[[^\]]+\]\s*)*(?:pub\s+)?struct\s+(\w+)(?:<([^>]+)>)?\s*(?:\{([^}]*)\}|;)",
+        r"(?s)#\[(?:struct_to_gts_schema|gts)\(([^)]+)\)\]\s*(?:#\[[^\]]+\]\s*)*(?:pub\s+)?struct\s+(\w+)(?:<([^>]+)>)?\s*(?:\{([^}]*)\}|;)",
     )?;
 
-    // Pre-compile field regex outside the loop
+    // Pre-compile field regexes outside the loop
     let field_re = Regex::new(r"(?m)^\s*(?:pub\s+)?(\w+)\s*:\s*([^,\n]+)")?;
+    let enum_values_re = Regex::new(r#"#\[gts\(.*\benum_values\s*=\s*"([^"]*)""#)?;
+    let skip_re = Regex::new(r"#\[gts\(.*\bskip\b")?;
+    let rename_re = Regex::new(r#"#\[(?:gts|serde)\(.*\brename\s*=\s*"([^"]*)""#)?;
 
     let mut results = Vec::new();
 
@@ -333,24 +392,63 @@ fn extract_and_generate_schemas(
             );
         }
 
-        // Parse struct fields
+        // Parse struct fields along with the doc comments and attributes preceding them
         let mut field_types = HashMap::new();
-
-        for field_cap in field_re.captures_iter(struct_body) {
-            let field_name = &field_cap[1];
-            let field_type = field_cap[2].trim().trim_end_matches(',');
-            field_types.insert(field_name.to_owned(), field_type.to_owned());
+        let mut field_attrs = HashMap::new();
+        let mut pending_doc: Vec<String> = Vec::new();
+        let mut pending_enum_values: Option<Vec<String>> = None;
+        let mut pending_skip = false;
+        let mut pending_rename: Option<String> = None;
+
+        for line in struct_body.lines() {
+            if let Some(doc) = parse_doc_comment(line) {
+                pending_doc.push(doc);
+            } else if line.trim_start().starts_with("#[") {
+                // Other field attributes (serde, gts, ...) don't break the doc comment
+                pending_skip |= skip_re.is_match(line);
+                if let Some(rename_cap) = rename_re.captures(line) {
+                    pending_rename = Some(rename_cap[1].to_owned());
+                }
+                if let Some(enum_cap) = enum_values_re.captures(line) {
+                    pending_enum_values = Some(
+                        enum_cap[1]
+                            .split(',')
+                            .map(|v| v.trim().to_owned())
+                            .filter(|v| !v.is_empty())
+                            .collect(),
+                    );
+                }
+            } else if let Some(field_cap) = field_re.captures(line) {
+                let field_name = &field_cap[1];
+                let field_type = field_cap[2].trim().trim_end_matches(',');
+                field_types.insert(field_name.to_owned(), field_type.to_owned());
+                let attrs = FieldAttrs {
+                    doc: (!pending_doc.is_empty()).then(|| pending_doc.join("\n")),
+                    enum_values: pending_enum_values.take(),
+                    skip: std::mem::take(&mut pending_skip),
+                    rename: pending_rename.take(),
+                };
+                field_attrs.insert(field_name.to_owned(), attrs);
+                pending_doc.clear();
+            } else {
+                pending_doc.clear();
+                pending_enum_values = None;
+                pending_skip = false;
+                pending_rename = None;
+            }
         }
 
         // Build JSON schema
-        let schema = build_json_schema(
+        let mut schema = build_json_schema(
             &attrs.schema_id,
             struct_name,
             attrs.description.as_deref(),
             attrs.properties.as_deref(),
             &attrs.base,
             &field_types,
+            &field_attrs,
         );
+        apply_schema_annotations(&mut schema, &attrs);
 
         // Create parent directories
         if let Some(parent) = output_path.parent() {
@@ -367,6 +465,29 @@ fn extract_and_generate_schemas(
     Ok(results)
 }
 
+/// Extract the text of a `///` doc comment or `#[doc = "..."]` attribute line
+fn parse_doc_comment(line: &str) -> Option<String> {
+    let line = line.trim();
+    if let Some(doc) = line.strip_prefix("///") {
+        // Skip `////` separator comments, which rustdoc doesn't treat as docs either
+        if doc.starts_with('/') {
+            return None;
+        }
+        return Some(doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_owned());
+    }
+    let value = line
+        .strip_prefix("#[doc")?
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start()
+        .strip_suffix(']')?
+        .trim_end();
+    value
+        .strip_prefix('"')?
+        .strip_suffix('"')
+        .map(|doc| doc.trim().to_owned())
+}
+
 /// Build a JSON Schema object from parsed metadata
 fn build_json_schema(
     schema_id: &str,
@@ -375,6 +496,7 @@ fn build_json_schema(
     properties_list: Option<&str>,
     base: &BaseAttr,
     field_types: &HashMap<String, String>,
+    field_attrs: &HashMap<String, FieldAttrs>,
 ) -> serde_json::Value {
     use serde_json::json;
 
@@ -382,22 +504,47 @@ fn build_json_schema(
     let mut required = Vec::new();
 
     // Determine which properties to include
-    let property_names: Vec<&str> = if let Some(props) = properties_list {
-        props.split(',').map(str::trim).collect()
-    } else {
-        // If no properties specified, include all fields
-        field_types.keys().map(String::as_str).collect()
+    let property_names: Vec<&str> = match properties_list {
+        Some(props) if props.trim() != "*" => props.split(',').map(str::trim).collect(),
+        // If no properties specified (or "*"), include all fields not marked #[gts(skip)]
+        _ => field_types
+            .keys()
+            .filter(|name| !field_attrs.get(*name).is_some_and(|attrs| attrs.skip))
+            .map(String::as_str)
+            .collect(),
     };
 
     for prop in &property_names {
         if let Some(field_type) = field_types.get(*prop) {
-            let (is_required, json_type_info) = rust_type_to_json_schema(field_type);
-
-            schema_properties.insert((*prop).to_owned(), json_type_info);
+            let (is_required, mut json_type_info) = rust_type_to_json_schema(field_type);
+            let attrs = field_attrs.get(*prop);
+            // Properties are keyed by the serialized (renamed) name, as serde writes them
+            let key = attrs
+                .and_then(|attrs| attrs.rename.clone())
+                .unwrap_or_else(|| (*prop).to_owned());
+
+            if let Some(attrs) = attrs {
+                if let Some(values) = &attrs.enum_values {
+                    json_type_info = if is_required {
+                        json!({ "type": "string", "enum": values })
+                    } else {
+                        let mut values = json!(values);
+                        if let Some(arr) = values.as_array_mut() {
+                            arr.push(serde_json::Value::Null);
+                        }
+                        json!({ "type": ["string", "null"], "enum": values })
+                    };
+                }
+                if let Some(doc) = &attrs.doc {
+                    json_type_info["description"] = json!(doc);
+                }
+            }
 
             if is_required {
-                required.push((*prop).to_owned());
+                required.push(key.clone());
             }
+
+            schema_properties.insert(key, json_type_info);
         }
     }
 
@@ -461,6 +608,17 @@ fn build_json_schema(
     }
 }
 
+/// Add schema-level annotations (`deprecated`, `examples`) from the macro attributes
+fn apply_schema_annotations(schema: &mut serde_json::Value, attrs: &MacroAttrs) {
+    if let Some(message) = &attrs.deprecated {
+        schema["deprecated"] = serde_json::Value::Bool(true);
+        schema["x-deprecated-message"] = serde_json::Value::String(message.clone());
+    }
+    if let Some(examples) = &attrs.examples {
+        schema["examples"] = examples.clone();
+    }
+}
+
 /// Derive parent schema ID from child schema ID
 /// e.g., "gts.x.core.events.type.v1~x.core.audit.event.v1~" -> "gts.x.core.events.type.v1~"
 fn derive_parent_schema_id(schema_id: &str) -> String {
@@ -715,6 +873,7 @@ mod tests {
             None, // Include all properties
             &BaseAttr::IsBase,
             &field_types,
+            &HashMap::new(),
         );
 
         assert_eq!(schema["$id"], "gts://gts.x.test.base.v1~");
@@ -750,6 +909,7 @@ mod tests {
             None,
             &BaseAttr::Parent("BaseStruct".to_owned()),
             &field_types,
+            &HashMap::new(),
         );
 
         assert_eq!(schema["$id"], "gts://gts.x.test.base.v1~x.test.child.v1~");
@@ -774,6 +934,53 @@ mod tests {
         assert_eq!(required[0], "child_field");
     }
 
+    #[test]
+    fn test_build_json_schema_field_descriptions() {
+        let mut field_types = HashMap::new();
+        field_types.insert("id".to_owned(), "String".to_owned());
+        field_types.insert("count".to_owned(), "i32".to_owned());
+        let mut field_attrs = HashMap::new();
+        field_attrs.insert(
+            "id".to_owned(),
+            FieldAttrs {
+                doc: Some("The unique identifier.".to_owned()),
+                ..FieldAttrs::default()
+            },
+        );
+
+        let schema = build_json_schema(
+            "gts.x.test.base.v1~",
+            "BaseStruct",
+            None,
+            None,
+            &BaseAttr::IsBase,
+            &field_types,
+            &field_attrs,
+        );
+
+        assert_eq!(
+            schema["properties"]["id"]["description"],
+            "The unique identifier."
+        );
+        assert!(schema["properties"]["count"].get("description").is_none());
+    }
+
+    #[test]
+    fn test_parse_doc_comment() {
+        assert_eq!(
+            parse_doc_comment("    /// The unique identifier."),
+            Some("The unique identifier.".to_owned())
+        );
+        assert_eq!(parse_doc_comment("///"), Some(String::new()));
+        assert_eq!(
+            parse_doc_comment(r#"    #[doc = " Topic name"]"#),
+            Some("Topic name".to_owned())
+        );
+        assert_eq!(parse_doc_comment("//// separator"), None);
+        assert_eq!(parse_doc_comment("// plain comment"), None);
+        assert_eq!(parse_doc_comment("pub id: String,"), None);
+    }
+
     #[test]
     fn test_parse_macro_attrs_with_schema_id() {
         let attr_body = r#"
@@ -787,6 +994,59 @@ mod tests {
         assert_eq!(attrs.dir_path, "schemas");
         assert!(matches!(attrs.base, BaseAttr::IsBase));
         assert!(attrs.description.is_none());
+        assert!(attrs.deprecated.is_none());
+    }
+
+    #[test]
+    fn test_parse_macro_attrs_deprecated() {
+        let attr_body = r#"
+            dir_path = "schemas",
+            base = true,
+            schema_id = "gts.x.custom.id.v1~",
+            deprecated = "Use IdV2 instead"
+        "#;
+
+        let attrs = parse_macro_attrs(attr_body).unwrap();
+        assert_eq!(attrs.deprecated.as_deref(), Some("Use IdV2 instead"));
+
+        let mut schema = serde_json::json!({});
+        apply_schema_annotations(&mut schema, &attrs);
+        assert_eq!(schema["deprecated"], true);
+        assert_eq!(schema["x-deprecated-message"], "Use IdV2 instead");
+    }
+
+    #[test]
+    fn test_parse_macro_attrs_examples() {
+        let raw_body = r##"
+            dir_path = "schemas",
+            base = true,
+            schema_id = "gts.x.custom.id.v1~",
+            examples = r#"[{"name": "Alice"}]"#
+        "##;
+        let attrs = parse_macro_attrs(raw_body).unwrap();
+        assert_eq!(attrs.examples, Some(serde_json::json!([{"name": "Alice"}])));
+
+        let escaped_body = r#"
+            dir_path = "schemas",
+            base = true,
+            schema_id = "gts.x.custom.id.v1~",
+            examples = "[{\"name\": \"Bob\"}]"
+        "#;
+        let attrs = parse_macro_attrs(escaped_body).unwrap();
+        assert_eq!(attrs.examples, Some(serde_json::json!([{"name": "Bob"}])));
+
+        let mut schema = serde_json::json!({});
+        apply_schema_annotations(&mut schema, &attrs);
+        assert_eq!(schema["examples"][0]["name"], "Bob");
+
+        // Not an array: ignored (the macro reports the error)
+        let object_body = r#"
+            dir_path = "schemas",
+            base = true,
+            schema_id = "gts.x.custom.id.v1~",
+            examples = "{}"
+        "#;
+        assert!(parse_macro_attrs(object_body).unwrap().examples.is_none());
     }
 
     #[test]
@@ -846,6 +1106,174 @@ pub struct Person {
         assert!(schema["properties"]["age"].is_object());
     }
 
+    #[test]
+    fn test_extract_and_generate_schemas_field_doc_comments() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.path().canonicalize().unwrap();
+
+        let test_file = temp_path.join("test.rs");
+        let content = r#"
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.order.v1~",
+    description = "An order"
+)]
+pub struct Order {
+    /// The unique identifier for this order.
+    pub id: GtsInstanceId,
+    /// Order total
+    /// in cents.
+    #[serde(default)]
+    pub total: i64,
+    pub note: Option<String>,
+    /// Current status
+    #[gts(enum_values = "Pending, Shipped")]
+    pub status: OrderStatus,
+    #[gts(enum_values = "Pending,Shipped")]
+    pub previous_status: Option<OrderStatus>,
+}
+"#;
+        fs::write(&test_file, content).unwrap();
+
+        let results = extract_and_generate_schemas(
+            content,
+            Some(temp_path.to_str().unwrap()),
+            &temp_path,
+            &test_file,
+        )
+        .unwrap();
+
+        let schema_content = fs::read_to_string(&results[0].1).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&schema_content).unwrap();
+
+        assert_eq!(
+            schema["properties"]["id"]["description"],
+            "The unique identifier for this order."
+        );
+        assert_eq!(
+            schema["properties"]["total"]["description"],
+            "Order total\nin cents."
+        );
+        assert!(schema["properties"]["note"].get("description").is_none());
+
+        // #[gts(enum_values = "...")] produces a string enum
+        assert_eq!(
+            schema["properties"]["status"],
+            serde_json::json!({
+                "type": "string",
+                "enum": ["Pending", "Shipped"],
+                "description": "Current status"
+            })
+        );
+        assert_eq!(
+            schema["properties"]["previous_status"]["enum"],
+            serde_json::json!(["Pending", "Shipped", null])
+        );
+    }
+
+    #[test]
+    fn test_extract_and_generate_schemas_renamed_fields() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.path().canonicalize().unwrap();
+
+        let test_file = temp_path.join("test.rs");
+        let content = r#"
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.renamed.v1~",
+    description = "Renamed fields",
+    properties = "id,event_type,note"
+)]
+pub struct Renamed {
+    pub id: GtsInstanceId,
+    /// Kind of event
+    #[gts(rename = "type")]
+    pub event_type: String,
+    #[serde(default, rename = "comment")]
+    pub note: Option<String>,
+}
+"#;
+        fs::write(&test_file, content).unwrap();
+
+        let results = extract_and_generate_schemas(
+            content,
+            Some(temp_path.to_str().unwrap()),
+            &temp_path,
+            &test_file,
+        )
+        .unwrap();
+
+        let schema_content = fs::read_to_string(&results[0].1).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&schema_content).unwrap();
+
+        // Properties and `required` use the serialized names
+        assert_eq!(schema["properties"]["type"]["description"], "Kind of event");
+        assert!(schema["properties"]["comment"].is_object());
+        assert!(schema["properties"].get("event_type").is_none());
+        assert!(schema["properties"].get("note").is_none());
+        assert_eq!(schema["required"], serde_json::json!(["id", "type"]));
+    }
+
+    #[test]
+    fn test_extract_and_generate_schemas_derive_style() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.path().canonicalize().unwrap();
+
+        let test_file = temp_path.join("test.rs");
+        let content = r#"
+#[derive(Debug, Serialize, Deserialize, JsonSchema, GtsSchema)]
+#[gts(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.derived.v1~",
+    description = "A derived struct",
+    properties = "*"
+)]
+pub struct DerivedV1 {
+    pub id: GtsInstanceId,
+    #[gts(rename = "title")]
+    pub name: String,
+    #[gts(skip)]
+    pub internal: u32,
+}
+"#;
+        fs::write(&test_file, content).unwrap();
+
+        let results = extract_and_generate_schemas(
+            content,
+            Some(temp_path.to_str().unwrap()),
+            &temp_path,
+            &test_file,
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "gts.x.test.derived.v1~");
+
+        let schema_content = fs::read_to_string(&results[0].1).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&schema_content).unwrap();
+        assert_eq!(schema["title"], "DerivedV1");
+        assert_eq!(schema["description"], "A derived struct");
+        assert!(schema["properties"]["id"].is_object());
+
+        // properties = "*" includes every field except #[gts(skip)] ones,
+        // keyed by their #[gts(rename)] name
+        assert!(schema["properties"]["title"].is_object());
+        assert!(schema["properties"].get("name").is_none());
+        assert!(schema["properties"].get("internal").is_none());
+    }
+
     #[test]
     fn test_extract_and_generate_schemas_with_parent() {
         use std::fs;
@@ -1,22 +1,10 @@
-use axum::{
-    Json, Router,
-    extract::{Path, Query, State},
-    http::StatusCode,
-    middleware,
-    response::IntoResponse,
-    routing::{get, post},
-};
+use axum::{Router, middleware};
 use gts::GtsOps;
-use serde::Deserialize;
 use serde_json::{Value, json};
-use std::sync::{Arc, Mutex};
 
-use crate::logging::LoggingMiddleware;
+pub use gts_server::AppState;
 
-#[derive(Clone)]
-pub struct AppState {
-    pub ops: Arc<Mutex<GtsOps>>,
-}
+use crate::logging::LoggingMiddleware;
 
 pub struct GtsHttpServer {
     ops: GtsOps,
@@ -45,9 +33,7 @@ impl GtsHttpServer {
     /// - The server encounters an error while serving requests
     pub async fn run(self) -> anyhow::Result<()> {
         let verbose = self.verbose;
-        let state = AppState {
-            ops: Arc::new(Mutex::new(self.ops)),
-        };
+        let state = AppState::new(self.ops);
 
         let app = Self::create_router(state, verbose);
 
@@ -60,24 +46,9 @@ impl GtsHttpServer {
         Ok(())
     }
 
+    /// Builds the shared `gts-server` router, adding request logging when `verbose >= 1`.
     pub fn create_router(state: AppState, verbose: u8) -> Router {
-        let mut router = Router::new()
-            .route("/entities", get(get_entities).post(add_entity))
-            .route("/entities/{gts_id}", get(get_entity))
-            .route("/entities/bulk", post(add_entities))
-            .route("/schemas", post(add_schema))
-            .route("/validate-id", get(validate_id))
-            .route("/extract-id", post(extract_id))
-            .route("/parse-id", get(parse_id))
-            .route("/match-id-pattern", get(match_id_pattern))
-            .route("/uuid", get(id_to_uuid))
-            .route("/validate-instance", post(validate_instance))
-            .route("/resolve-relationships", get(schema_graph))
-            .route("/compatibility", get(compatibility))
-            .route("/cast", post(cast))
-            .route("/query", get(query))
-            .route("/attr", get(attr))
-            .with_state(state);
+        let mut router = gts_server::create_router(state);
 
         // Add custom logging middleware if verbose >= 1
         if verbose >= 1 {
@@ -114,268 +85,3 @@ impl GtsHttpServer {
         })
     }
 }
-
-// Query parameters
-#[derive(Deserialize)]
-struct GtsIdQuery {
-    gts_id: String,
-}
-
-#[derive(Deserialize)]
-struct MatchIdQuery {
-    candidate: String,
-    pattern: String,
-}
-
-#[derive(Deserialize)]
-struct CompatibilityQuery {
-    old_schema_id: String,
-    new_schema_id: String,
-}
-
-#[derive(Deserialize)]
-struct QueryParams {
-    expr: String,
-    #[serde(default = "default_limit")]
-    limit: usize,
-}
-
-#[derive(Deserialize)]
-struct AttrQuery {
-    gts_with_path: String,
-}
-
-#[derive(Deserialize)]
-struct LimitQuery {
-    #[serde(default = "default_limit")]
-    limit: usize,
-}
-
-#[derive(Deserialize)]
-struct AddEntityQuery {
-    #[serde(default)]
-    validate: bool,
-}
-
-fn default_limit() -> usize {
-    100
-}
-
-#[derive(Deserialize)]
-struct SchemaRegister {
-    type_id: String,
-    #[serde(rename = "schema")]
-    schema_content: Value,
-}
-
-#[derive(Deserialize, serde::Serialize)]
-struct CastRequest {
-    instance_id: String,
-    to_schema_id: String,
-}
-
-#[derive(Deserialize, serde::Serialize)]
-struct ValidateInstanceRequest {
-    instance_id: String,
-}
-
-// Helper function to lock mutex or return error response
-fn lock_ops(
-    mutex: &Arc<Mutex<GtsOps>>,
-) -> Result<std::sync::MutexGuard<'_, GtsOps>, impl IntoResponse> {
-    mutex.lock().map_err(|_| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({"error": "Server state corrupted"})),
-        )
-    })
-}
-
-// Async Handlers
-async fn get_entities(
-    State(state): State<AppState>,
-    Query(params): Query<LimitQuery>,
-) -> impl IntoResponse {
-    let ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.get_entities(params.limit);
-    Json(result).into_response()
-}
-
-async fn get_entity(
-    State(state): State<AppState>,
-    Path(gts_id): Path<String>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.get_entity(&gts_id);
-    Json(result).into_response()
-}
-
-async fn add_entity(
-    State(state): State<AppState>,
-    Query(params): Query<AddEntityQuery>,
-    Json(body): Json<Value>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.add_entity(&body, params.validate);
-    if result.ok {
-        (StatusCode::OK, Json(result)).into_response()
-    } else {
-        (StatusCode::UNPROCESSABLE_ENTITY, Json(result)).into_response()
-    }
-}
-
-async fn add_entities(
-    State(state): State<AppState>,
-    Json(body): Json<Vec<Value>>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.add_entities(&body);
-    Json(result).into_response()
-}
-
-async fn add_schema(
-    State(state): State<AppState>,
-    Json(body): Json<SchemaRegister>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.add_schema(body.type_id, &body.schema_content);
-    Json(result).into_response()
-}
-
-async fn validate_id(
-    State(state): State<AppState>,
-    Query(params): Query<GtsIdQuery>,
-) -> impl IntoResponse {
-    let _ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = GtsOps::validate_id(&params.gts_id);
-    Json(result).into_response()
-}
-
-async fn extract_id(State(state): State<AppState>, Json(body): Json<Value>) -> impl IntoResponse {
-    let ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.extract_id(&body);
-    Json(result).into_response()
-}
-
-async fn parse_id(
-    State(state): State<AppState>,
-    Query(params): Query<GtsIdQuery>,
-) -> impl IntoResponse {
-    let _ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = GtsOps::parse_id(&params.gts_id);
-    Json(result).into_response()
-}
-
-async fn match_id_pattern(
-    State(state): State<AppState>,
-    Query(params): Query<MatchIdQuery>,
-) -> impl IntoResponse {
-    let _ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = GtsOps::match_id_pattern(&params.candidate, &params.pattern);
-    Json(result).into_response()
-}
-
-async fn id_to_uuid(
-    State(state): State<AppState>,
-    Query(params): Query<GtsIdQuery>,
-) -> impl IntoResponse {
-    let _ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = GtsOps::uuid(&params.gts_id);
-    Json(result).into_response()
-}
-
-async fn validate_instance(
-    State(state): State<AppState>,
-    Json(body): Json<ValidateInstanceRequest>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.validate_instance(&body.instance_id);
-    Json(result).into_response()
-}
-
-async fn schema_graph(
-    State(state): State<AppState>,
-    Query(params): Query<GtsIdQuery>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.schema_graph(&params.gts_id);
-    Json(result).into_response()
-}
-
-async fn compatibility(
-    State(state): State<AppState>,
-    Query(params): Query<CompatibilityQuery>,
-) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.compatibility(&params.old_schema_id, &params.new_schema_id);
-    Json(result).into_response()
-}
-
-async fn cast(State(state): State<AppState>, Json(body): Json<CastRequest>) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.cast(&body.instance_id, &body.to_schema_id);
-    Json(result).into_response()
-}
-
-async fn query(
-    State(state): State<AppState>,
-    Query(params): Query<QueryParams>,
-) -> impl IntoResponse {
-    let ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.query(&params.expr, params.limit);
-    Json(result).into_response()
-}
-
-async fn attr(State(state): State<AppState>, Query(params): Query<AttrQuery>) -> impl IntoResponse {
-    let mut ops = match lock_ops(&state.ops) {
-        Ok(guard) => guard,
-        Err(response) => return response.into_response(),
-    };
-    let result = ops.attr(&params.gts_with_path);
-    Json(result).into_response()
-}
@@ -0,0 +1,86 @@
+#ifndef GTS_FFI_H
+#define GTS_FFI_H
+
+/* Generated by cbindgen from gts-ffi/src/lib.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Opaque result of [`gts_id_parse`], mirroring `gts::ops::GtsIdParseResult`.
+ */
+typedef struct GtsIdParseResultC GtsIdParseResultC;
+
+/**
+ * Returns `true` if `id` is a valid GTS identifier.
+ *
+ * # Safety
+ * `id` must be NULL or a valid NUL-terminated string.
+ */
+bool gts_id_is_valid(const char *id);
+
+/**
+ * Parse a GTS ID (or wildcard pattern).
+ *
+ * Returns NULL if `id` is NULL or not UTF-8. Otherwise the result must be
+ * released with [`gts_id_parse_free`], even when parsing failed.
+ *
+ * # Safety
+ * `id` must be NULL or a valid NUL-terminated string.
+ */
+struct GtsIdParseResultC *gts_id_parse(const char *id);
+
+/**
+ * Free a result returned by [`gts_id_parse`]. Passing NULL is a no-op.
+ *
+ * # Safety
+ * `result` must be NULL or a pointer returned by [`gts_id_parse`] that has not been freed.
+ */
+void gts_id_parse_free(struct GtsIdParseResultC *result);
+
+/**
+ * Returns `true` if the ID was parsed successfully.
+ *
+ * # Safety
+ * `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+ */
+bool gts_id_parse_ok(const struct GtsIdParseResultC *result);
+
+/**
+ * Returns `true` if the parsed ID is a schema (type) ID ending with `~`.
+ *
+ * # Safety
+ * `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+ */
+bool gts_id_parse_is_schema(const struct GtsIdParseResultC *result);
+
+/**
+ * Returns `true` if the input was a wildcard pattern.
+ *
+ * # Safety
+ * `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+ */
+bool gts_id_parse_is_wildcard(const struct GtsIdParseResultC *result);
+
+/**
+ * Returns the number of segments in the parsed ID (0 on failure).
+ *
+ * # Safety
+ * `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+ */
+size_t gts_id_parse_segment_count(const struct GtsIdParseResultC *result);
+
+/**
+ * Returns the parse error message (empty on success, NULL if `result` is NULL).
+ *
+ * The string is owned by `result` and valid until [`gts_id_parse_free`].
+ *
+ * # Safety
+ * `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+ */
+const char *gts_id_parse_error(const struct GtsIdParseResultC *result);
+
+#endif  /* GTS_FFI_H */
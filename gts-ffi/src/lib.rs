@@ -0,0 +1,200 @@
+//! C ABI bindings for GTS ID validation and parsing.
+//!
+//! The generated header is `include/gts.h`. Strings passed in must be valid,
+//! NUL-terminated UTF-8; parse results are owned by the library and must be
+//! released with [`gts_id_parse_free`].
+
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+use gts::GtsOps;
+
+/// Opaque result of [`gts_id_parse`], mirroring `gts::ops::GtsIdParseResult`.
+#[allow(clippy::struct_excessive_bools)]
+pub struct GtsIdParseResultC {
+    ok: bool,
+    is_schema: bool,
+    is_wildcard: bool,
+    segment_count: usize,
+    error: CString,
+}
+
+/// Borrow a C string as `&str`, or `None` for NULL / invalid UTF-8.
+///
+/// # Safety
+/// `ptr` must be NULL or point to a valid NUL-terminated string that outlives `'a`.
+unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: the caller guarantees `ptr` is a valid NUL-terminated string.
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Returns `true` if `id` is a valid GTS identifier.
+///
+/// # Safety
+/// `id` must be NULL or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_is_valid(id: *const c_char) -> bool {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { str_from_ptr(id) }.is_some_and(|id| GtsOps::validate_id(id).valid)
+}
+
+/// Parse a GTS ID (or wildcard pattern).
+///
+/// Returns NULL if `id` is NULL or not UTF-8. Otherwise the result must be
+/// released with [`gts_id_parse_free`], even when parsing failed.
+///
+/// # Safety
+/// `id` must be NULL or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse(id: *const c_char) -> *mut GtsIdParseResultC {
+    // SAFETY: forwarded from the caller's contract.
+    let Some(id) = (unsafe { str_from_ptr(id) }) else {
+        return ptr::null_mut();
+    };
+
+    let result = GtsOps::parse_id(id);
+    Box::into_raw(Box::new(GtsIdParseResultC {
+        ok: result.ok,
+        is_schema: result.is_schema.unwrap_or(false),
+        is_wildcard: result.is_wildcard,
+        segment_count: result.segments.len(),
+        // Error messages never contain NUL bytes; fall back to empty if one did
+        error: CString::new(result.error).unwrap_or_default(),
+    }))
+}
+
+/// Free a result returned by [`gts_id_parse`]. Passing NULL is a no-op.
+///
+/// # Safety
+/// `result` must be NULL or a pointer returned by [`gts_id_parse`] that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_free(result: *mut GtsIdParseResultC) {
+    if !result.is_null() {
+        // SAFETY: the pointer came from `Box::into_raw` in `gts_id_parse` and is freed once.
+        drop(unsafe { Box::from_raw(result) });
+    }
+}
+
+/// Borrow a parse result, or `None` for NULL.
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+unsafe fn result_ref<'a>(result: *const GtsIdParseResultC) -> Option<&'a GtsIdParseResultC> {
+    // SAFETY: the caller guarantees the pointer is NULL or live.
+    unsafe { result.as_ref() }
+}
+
+/// Returns `true` if the ID was parsed successfully.
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_ok(result: *const GtsIdParseResultC) -> bool {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { result_ref(result) }.is_some_and(|r| r.ok)
+}
+
+/// Returns `true` if the parsed ID is a schema (type) ID ending with `~`.
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_is_schema(result: *const GtsIdParseResultC) -> bool {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { result_ref(result) }.is_some_and(|r| r.is_schema)
+}
+
+/// Returns `true` if the input was a wildcard pattern.
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_is_wildcard(result: *const GtsIdParseResultC) -> bool {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { result_ref(result) }.is_some_and(|r| r.is_wildcard)
+}
+
+/// Returns the number of segments in the parsed ID (0 on failure).
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_segment_count(result: *const GtsIdParseResultC) -> usize {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { result_ref(result) }.map_or(0, |r| r.segment_count)
+}
+
+/// Returns the parse error message (empty on success, NULL if `result` is NULL).
+///
+/// The string is owned by `result` and valid until [`gts_id_parse_free`].
+///
+/// # Safety
+/// `result` must be NULL or a live pointer returned by [`gts_id_parse`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gts_id_parse_error(result: *const GtsIdParseResultC) -> *const c_char {
+    // SAFETY: forwarded from the caller's contract.
+    unsafe { result_ref(result) }.map_or(ptr::null(), |r| r.error.as_ptr())
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::undocumented_unsafe_blocks
+)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gts_id_is_valid() {
+        let valid = CString::new("gts.x.core.events.type.v1~").unwrap();
+        let invalid = CString::new("not-a-gts-id").unwrap();
+        unsafe {
+            assert!(gts_id_is_valid(valid.as_ptr()));
+            assert!(!gts_id_is_valid(invalid.as_ptr()));
+            assert!(!gts_id_is_valid(ptr::null()));
+        }
+    }
+
+    #[test]
+    fn test_gts_id_parse() {
+        let id = CString::new("gts.x.core.events.type.v1~x.core.audit.event.v1.2~").unwrap();
+        unsafe {
+            let result = gts_id_parse(id.as_ptr());
+            assert!(!result.is_null());
+            assert!(gts_id_parse_ok(result));
+            assert!(gts_id_parse_is_schema(result));
+            assert!(!gts_id_parse_is_wildcard(result));
+            assert_eq!(gts_id_parse_segment_count(result), 2);
+            assert_eq!(
+                CStr::from_ptr(gts_id_parse_error(result)).to_str().unwrap(),
+                ""
+            );
+            gts_id_parse_free(result);
+        }
+    }
+
+    #[test]
+    fn test_gts_id_parse_failure() {
+        let id = CString::new("gts.bad").unwrap();
+        unsafe {
+            let result = gts_id_parse(id.as_ptr());
+            assert!(!gts_id_parse_ok(result));
+            assert_eq!(gts_id_parse_segment_count(result), 0);
+            assert!(
+                !CStr::from_ptr(gts_id_parse_error(result))
+                    .to_bytes()
+                    .is_empty()
+            );
+            gts_id_parse_free(result);
+
+            assert!(gts_id_parse(ptr::null()).is_null());
+            assert!(!gts_id_parse_ok(ptr::null()));
+            assert!(gts_id_parse_error(ptr::null()).is_null());
+            gts_id_parse_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Generates the `GtsService` server stubs from `proto/gts.proto`.
+//!
+//! `protoc` is taken from `protoc-bin-vendored`, so no system install is needed.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto/gts.proto");
+
+    let mut config = prost_build::Config::new();
+    config.protoc_executable(protoc_bin_vendored::protoc_bin_path()?);
+
+    tonic_prost_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .compile_with_config(config, &["proto/gts.proto"], &["proto"])?;
+    Ok(())
+}
@@ -0,0 +1,270 @@
+//! gRPC service exposing [`GtsOps`] through `tonic`.
+//!
+//! The protobuf definition lives in `proto/gts.proto`. [`GtsGrpcService`] implements
+//! the generated `GtsService` trait by delegating to a shared [`GtsOps`]; wrap it in
+//! [`GtsServiceServer`] to mount it on a `tonic` server.
+
+use gts::{GtsOps, StoreError};
+use serde_json::Value;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tonic::{Request, Response, Status};
+
+/// Code generated from `proto/gts.proto`.
+#[allow(clippy::all, clippy::pedantic, missing_docs)]
+pub mod proto {
+    tonic::include_proto!("gts.v1");
+}
+
+pub use proto::gts_service_server::{GtsService, GtsServiceServer};
+
+use proto::{
+    AddEntityRequest, AddEntityResponse, AddSchemaRequest, AddSchemaResponse, CastRequest,
+    CastResponse, CompatibilityRequest, CompatibilityResponse, IdSegment, ParseIdRequest,
+    ParseIdResponse, QueryRequest, QueryResponse, ValidateIdRequest, ValidateIdResponse,
+    ValidateInstanceRequest, ValidateSchemaRequest, ValidationResponse,
+};
+
+/// Maps a store error to the closest gRPC status code.
+#[must_use]
+pub fn status_from_store_error(error: &StoreError) -> Status {
+    let message = error.to_string();
+    match error {
+        StoreError::ObjectNotFound(_)
+        | StoreError::SchemaNotFound(_)
+        | StoreError::EntityNotFound(_)
+        | StoreError::SchemaForInstanceNotFound(_) => Status::not_found(message),
+        StoreError::CastFromSchemaNotAllowed(_)
+        | StoreError::InvalidEntity
+        | StoreError::InvalidSchemaId
+        | StoreError::InvalidRef(_)
+        | StoreError::ValidationError(_) => Status::invalid_argument(message),
+        StoreError::DuplicateEntity(_) => Status::already_exists(message),
+        StoreError::CircularReference(_) => Status::failed_precondition(message),
+        StoreError::ImportError(_) => Status::internal(message),
+    }
+}
+
+/// `GtsService` implementation backed by a shared [`GtsOps`].
+#[derive(Clone)]
+pub struct GtsGrpcService {
+    ops: Arc<Mutex<GtsOps>>,
+}
+
+impl GtsGrpcService {
+    #[must_use]
+    pub fn new(ops: GtsOps) -> Self {
+        Self {
+            ops: Arc::new(Mutex::new(ops)),
+        }
+    }
+
+    /// Creates a service sharing `ops` with other handlers (e.g. the HTTP server).
+    #[must_use]
+    pub fn from_shared(ops: Arc<Mutex<GtsOps>>) -> Self {
+        Self { ops }
+    }
+
+    /// Wraps the service in the generated `tonic` server.
+    #[must_use]
+    pub fn into_server(self) -> GtsServiceServer<Self> {
+        GtsServiceServer::new(self)
+    }
+
+    fn lock_ops(&self) -> Result<MutexGuard<'_, GtsOps>, Status> {
+        self.ops
+            .lock()
+            .map_err(|_| Status::internal("Server state corrupted"))
+    }
+}
+
+fn parse_json(field: &str, json: &str) -> Result<Value, Status> {
+    serde_json::from_str(json)
+        .map_err(|e| Status::invalid_argument(format!("Invalid JSON in '{field}': {e}")))
+}
+
+/// Converts a validation outcome: failed validation is a normal response, anything
+/// else (missing entity, bad ID) is an error status.
+fn validation_response(
+    id: &str,
+    result: Result<(), StoreError>,
+) -> Result<ValidationResponse, Status> {
+    match result {
+        Ok(()) => Ok(ValidationResponse {
+            id: id.to_owned(),
+            ok: true,
+            error: String::new(),
+        }),
+        Err(StoreError::ValidationError(error)) => Ok(ValidationResponse {
+            id: id.to_owned(),
+            ok: false,
+            error,
+        }),
+        Err(e) => Err(status_from_store_error(&e)),
+    }
+}
+
+#[tonic::async_trait]
+impl GtsService for GtsGrpcService {
+    async fn validate_id(
+        &self,
+        request: Request<ValidateIdRequest>,
+    ) -> Result<Response<ValidateIdResponse>, Status> {
+        let result = GtsOps::validate_id(&request.into_inner().gts_id);
+        Ok(Response::new(ValidateIdResponse {
+            id: result.id,
+            valid: result.valid,
+            error: result.error,
+            is_schema: result.is_schema,
+            is_wildcard: result.is_wildcard,
+        }))
+    }
+
+    async fn parse_id(
+        &self,
+        request: Request<ParseIdRequest>,
+    ) -> Result<Response<ParseIdResponse>, Status> {
+        let result = GtsOps::parse_id(&request.into_inner().gts_id);
+        Ok(Response::new(ParseIdResponse {
+            id: result.id,
+            ok: result.ok,
+            segments: result
+                .segments
+                .into_iter()
+                .map(|seg| IdSegment {
+                    vendor: seg.vendor,
+                    package: seg.package,
+                    namespace: seg.namespace,
+                    type_name: seg.type_name,
+                    ver_major: seg.ver_major,
+                    ver_minor: seg.ver_minor,
+                    ver_patch: seg.ver_patch,
+                    is_type: seg.is_type,
+                })
+                .collect(),
+            error: result.error,
+            is_schema: result.is_schema,
+            is_wildcard: result.is_wildcard,
+        }))
+    }
+
+    async fn validate_instance(
+        &self,
+        request: Request<ValidateInstanceRequest>,
+    ) -> Result<Response<ValidationResponse>, Status> {
+        let instance_id = request.into_inner().instance_id;
+        let result = self.lock_ops()?.store.validate_instance(&instance_id);
+        validation_response(&instance_id, result).map(Response::new)
+    }
+
+    async fn validate_schema(
+        &self,
+        request: Request<ValidateSchemaRequest>,
+    ) -> Result<Response<ValidationResponse>, Status> {
+        let schema_id = request.into_inner().schema_id;
+        let result = self.lock_ops()?.store.validate_schema(&schema_id);
+        validation_response(&schema_id, result).map(Response::new)
+    }
+
+    async fn cast(&self, request: Request<CastRequest>) -> Result<Response<CastResponse>, Status> {
+        let CastRequest {
+            instance_id,
+            to_schema_id,
+        } = request.into_inner();
+        let result = self
+            .lock_ops()?
+            .store
+            .cast(&instance_id, &to_schema_id)
+            .map_err(|e| status_from_store_error(&e))?;
+        Ok(Response::new(CastResponse {
+            from_id: result.from_id,
+            to_id: result.to_id,
+            direction: result.direction,
+            is_fully_compatible: result.is_fully_compatible,
+            is_backward_compatible: result.is_backward_compatible,
+            is_forward_compatible: result.is_forward_compatible,
+            incompatibility_reasons: result.incompatibility_reasons,
+            casted_entity_json: result.casted_entity.map(|entity| entity.to_string()),
+        }))
+    }
+
+    async fn query(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<QueryResponse>, Status> {
+        let QueryRequest { expr, limit } = request.into_inner();
+        let limit = if limit == 0 { 100 } else { limit };
+        let result = self
+            .lock_ops()?
+            .query(&expr, usize::try_from(limit).unwrap_or(usize::MAX));
+        if !result.error.is_empty() {
+            return Err(Status::invalid_argument(result.error));
+        }
+        Ok(Response::new(QueryResponse {
+            count: u32::try_from(result.count).unwrap_or(u32::MAX),
+            limit,
+            results_json: result.results.iter().map(Value::to_string).collect(),
+        }))
+    }
+
+    async fn add_entity(
+        &self,
+        request: Request<AddEntityRequest>,
+    ) -> Result<Response<AddEntityResponse>, Status> {
+        let AddEntityRequest {
+            content_json,
+            validate,
+        } = request.into_inner();
+        let content = parse_json("content_json", &content_json)?;
+        let result = self.lock_ops()?.add_entity(&content, validate);
+        if !result.ok {
+            return Err(Status::invalid_argument(result.error));
+        }
+        Ok(Response::new(AddEntityResponse {
+            id: result.id,
+            schema_id: result.schema_id,
+            is_schema: result.is_schema,
+        }))
+    }
+
+    async fn add_schema(
+        &self,
+        request: Request<AddSchemaRequest>,
+    ) -> Result<Response<AddSchemaResponse>, Status> {
+        let AddSchemaRequest {
+            type_id,
+            schema_json,
+        } = request.into_inner();
+        let schema = parse_json("schema_json", &schema_json)?;
+        let result = self.lock_ops()?.add_schema(type_id, &schema);
+        if !result.ok {
+            return Err(Status::invalid_argument(result.error));
+        }
+        Ok(Response::new(AddSchemaResponse { id: result.id }))
+    }
+
+    async fn compatibility(
+        &self,
+        request: Request<CompatibilityRequest>,
+    ) -> Result<Response<CompatibilityResponse>, Status> {
+        let CompatibilityRequest {
+            old_schema_id,
+            new_schema_id,
+        } = request.into_inner();
+        let result = self
+            .lock_ops()?
+            .compatibility(&old_schema_id, &new_schema_id);
+        if let Some(error) = result.error {
+            return Err(Status::invalid_argument(error));
+        }
+        Ok(Response::new(CompatibilityResponse {
+            from_id: result.from_id,
+            to_id: result.to_id,
+            direction: result.direction,
+            is_fully_compatible: result.is_fully_compatible,
+            is_backward_compatible: result.is_backward_compatible,
+            is_forward_compatible: result.is_forward_compatible,
+            backward_errors: result.backward_errors,
+            forward_errors: result.forward_errors,
+        }))
+    }
+}
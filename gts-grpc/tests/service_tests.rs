@@ -0,0 +1,174 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use gts::GtsOps;
+use gts_grpc::proto::{
+    AddEntityRequest, CastRequest, CompatibilityRequest, ParseIdRequest, QueryRequest,
+    ValidateIdRequest, ValidateInstanceRequest, ValidateSchemaRequest,
+};
+use gts_grpc::{GtsGrpcService, GtsService};
+use serde_json::{Value, json};
+use tonic::{Code, Request};
+
+const SCHEMA_V1: &str = "gts.x.test.grpc.user.v1.0~";
+const SCHEMA_V1_1: &str = "gts.x.test.grpc.user.v1.1~";
+const INSTANCE_ID: &str = "gts.x.test.grpc.user.v1.0~x.test.grpc.alice.v1";
+
+async fn add(service: &GtsGrpcService, content: &Value) {
+    service
+        .add_entity(Request::new(AddEntityRequest {
+            content_json: content.to_string(),
+            validate: false,
+        }))
+        .await
+        .unwrap();
+}
+
+async fn create_service() -> GtsGrpcService {
+    let service = GtsGrpcService::new(GtsOps::new(None, None, 0));
+    add(
+        &service,
+        &json!({
+            "$id": format!("gts://{SCHEMA_V1}"),
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {"id": {"type": "string"}, "name": {"type": "string"}}
+        }),
+    )
+    .await;
+    add(
+        &service,
+        &json!({
+            "$id": format!("gts://{SCHEMA_V1_1}"),
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {
+                "id": {"type": "string"},
+                "name": {"type": "string"},
+                "age": {"type": "integer", "default": 0}
+            }
+        }),
+    )
+    .await;
+    add(&service, &json!({"id": INSTANCE_ID, "name": "Alice"})).await;
+    service
+}
+
+#[tokio::test]
+async fn test_validate_and_parse_id() {
+    let service = GtsGrpcService::new(GtsOps::new(None, None, 0));
+
+    let valid = service
+        .validate_id(Request::new(ValidateIdRequest {
+            gts_id: SCHEMA_V1.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(valid.valid);
+    assert_eq!(valid.is_schema, Some(true));
+
+    let parsed = service
+        .parse_id(Request::new(ParseIdRequest {
+            gts_id: INSTANCE_ID.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(parsed.ok);
+    assert_eq!(parsed.segments.len(), 2);
+    assert_eq!(parsed.segments[0].type_name, "user");
+    assert_eq!(parsed.segments[0].ver_minor, Some(0));
+}
+
+#[tokio::test]
+async fn test_validate_instance_and_schema() {
+    let service = create_service().await;
+
+    let result = service
+        .validate_instance(Request::new(ValidateInstanceRequest {
+            instance_id: INSTANCE_ID.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.ok, "{}", result.error);
+
+    let result = service
+        .validate_schema(Request::new(ValidateSchemaRequest {
+            schema_id: SCHEMA_V1.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.ok, "{}", result.error);
+
+    let status = service
+        .validate_instance(Request::new(ValidateInstanceRequest {
+            instance_id: "gts.x.test.grpc.user.v1.0~x.test.grpc.missing.v1".to_owned(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_cast_and_compatibility() {
+    let service = create_service().await;
+
+    let cast = service
+        .cast(Request::new(CastRequest {
+            instance_id: INSTANCE_ID.to_owned(),
+            to_schema_id: SCHEMA_V1_1.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    let casted: Value = serde_json::from_str(&cast.casted_entity_json.unwrap()).unwrap();
+    assert_eq!(casted["age"], 0);
+
+    let compat = service
+        .compatibility(Request::new(CompatibilityRequest {
+            old_schema_id: SCHEMA_V1.to_owned(),
+            new_schema_id: SCHEMA_V1_1.to_owned(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(compat.is_backward_compatible);
+
+    let status = service
+        .cast(Request::new(CastRequest {
+            instance_id: SCHEMA_V1.to_owned(),
+            to_schema_id: SCHEMA_V1_1.to_owned(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_query_and_add_entity_errors() {
+    let service = create_service().await;
+
+    let result = service
+        .query(Request::new(QueryRequest {
+            expr: "gts.x.test.grpc.user.*".to_owned(),
+            limit: 0,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(result.limit, 100);
+    assert_eq!(result.count, 3);
+
+    let status = service
+        .add_entity(Request::new(AddEntityRequest {
+            content_json: "{not json".to_owned(),
+            validate: false,
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
@@ -14,67 +14,105 @@ const ID_FIELD_NAMES: &[&str] = &["$id", "id", "gts_id", "gtsId"];
 const TYPE_FIELD_NAMES: &[&str] = &["type", "r#type", "gts_type", "gtsType", "schema"];
 const SERDE_TYPE_RENAMES: &[&str] = &["type", "gts_type", "gtsType", "schema"];
 
-/// Represents a parsed version (major and optional minor)
+/// Represents a parsed version (major, optional minor/patch and optional pre-release label)
 #[derive(Debug, PartialEq)]
 struct Version {
     major: u32,
     minor: Option<u32>,
+    /// Only present together with `minor`
+    patch: Option<u32>,
+    /// Lowercase pre-release label, e.g. `beta` for `V1Beta` / `v1-beta`
+    pre_release: Option<String>,
 }
 
 impl Version {
-    /// Format version for struct name suffix (e.g., "V1" or "`V1_0`")
+    /// Format version for struct name suffix (e.g., "V1", "`V1_0`", "`V1_2_3`" or "`V1Beta`")
     fn to_struct_suffix(&self) -> String {
-        match self.minor {
-            Some(minor) => format!("V{}_{}", self.major, minor),
-            None => format!("V{}", self.major),
+        let mut suffix = format!("V{}", self.major);
+        for part in [self.minor, self.patch].into_iter().flatten() {
+            suffix.push('_');
+            suffix.push_str(&part.to_string());
         }
+        if let Some(pre) = &self.pre_release {
+            let mut chars = pre.chars();
+            if let Some(first) = chars.next() {
+                suffix.push(first.to_ascii_uppercase());
+                suffix.push_str(chars.as_str());
+            }
+        }
+        suffix
     }
 
-    /// Format version for schema ID (e.g., "v1" or "v1.0")
+    /// Format version for schema ID (e.g., "v1", "v1.0", "v1.2.3" or "v1-beta")
     fn to_schema_version(&self) -> String {
-        match self.minor {
-            Some(minor) => format!("v{}.{}", self.major, minor),
-            None => format!("v{}", self.major),
+        let mut version = format!("v{}", self.major);
+        for part in [self.minor, self.patch].into_iter().flatten() {
+            version.push('.');
+            version.push_str(&part.to_string());
+        }
+        if let Some(pre) = &self.pre_release {
+            version.push('-');
+            version.push_str(pre);
         }
+        version
     }
 }
 
-/// Extract version from struct name suffix (e.g., `BaseEventV1` -> V1, `BaseEventV2_0` -> V2.0)
-fn extract_struct_version(struct_name: &str) -> Option<Version> {
-    // Look for pattern: V<major> or V<major>_<minor> at the end of the name
-    // We need to find the last 'V' followed by digits
-    let bytes = struct_name.as_bytes();
-    let mut v_pos = None;
-
-    // Find the last 'V' that starts a version suffix
-    for i in (0..bytes.len()).rev() {
-        // Check if 'V' is followed by at least one digit
-        if bytes[i] == b'V' && i + 1 < bytes.len() && bytes[i + 1].is_ascii_digit() {
-            v_pos = Some(i);
-            break;
-        }
+/// Parse `MAJOR[<sep>MINOR[<sep>PATCH]]` into its numeric components
+fn parse_version_numbers(
+    numbers: &str,
+    separator: char,
+    pre_release: Option<String>,
+) -> Option<Version> {
+    let mut parts = numbers.split(separator).map(str::parse::<u32>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().transpose().ok()?;
+    let patch = parts.next().transpose().ok()?;
+    if parts.next().is_some() {
+        return None;
     }
+    Some(Version {
+        major,
+        minor,
+        patch,
+        pre_release,
+    })
+}
+
+/// Check that a pre-release label is non-empty and purely alphabetic (e.g. `Beta`, `rc`)
+fn is_pre_release_label(label: &str) -> bool {
+    !label.is_empty() && label.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Find the position of the last 'V' followed by a digit, which starts the struct version suffix
+fn find_struct_version_start(struct_name: &str) -> Option<usize> {
+    let bytes = struct_name.as_bytes();
+    (0..bytes.len().saturating_sub(1))
+        .rev()
+        .find(|&i| bytes[i] == b'V' && bytes[i + 1].is_ascii_digit())
+}
 
-    let v_pos = v_pos?;
+/// Extract version from struct name suffix (e.g., `BaseEventV1` -> V1, `BaseEventV2_0` -> V2.0,
+/// `BaseEventV1_2_3` -> V1.2.3, `BaseEventV1Beta` -> V1 with pre-release `beta`)
+fn extract_struct_version(struct_name: &str) -> Option<Version> {
+    // Look for pattern: V<major>, V<major>_<minor> or V<major>_<minor>_<patch> at the end of the name
+    let v_pos = find_struct_version_start(struct_name)?;
     let version_part = &struct_name[v_pos + 1..]; // Skip the 'V'
 
-    // Parse major_minor pattern
-    if let Some(underscore_pos) = version_part.find('_') {
-        // Has minor version: V<major>_<minor>
-        let major_str = &version_part[..underscore_pos];
-        let minor_str = &version_part[underscore_pos + 1..];
-
-        let major = major_str.parse::<u32>().ok()?;
-        let minor = minor_str.parse::<u32>().ok()?;
-        Some(Version {
-            major,
-            minor: Some(minor),
-        })
+    // Split off an optional pre-release suffix: V<major>[_<minor>[_<patch>]]<Label>
+    let label_pos = version_part
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(version_part.len());
+    let (version_part, label) = version_part.split_at(label_pos);
+    let pre_release = if label.is_empty() {
+        None
+    } else if is_pre_release_label(label) {
+        Some(label.to_ascii_lowercase())
     } else {
-        // Only major version: V<major>
-        let major = version_part.parse::<u32>().ok()?;
-        Some(Version { major, minor: None })
-    }
+        return None;
+    };
+
+    parse_version_numbers(version_part, '_', pre_release)
 }
 
 /// Extract version from `schema_id`'s last segment (e.g., `gts.x.core.events.type.v1~` -> v1)
@@ -97,7 +135,7 @@ fn extract_schema_version(schema_id: &str) -> Option<Version> {
     };
 
     // Now find the version in this segment
-    // Format is: something.vMAJOR or something.vMAJOR.MINOR
+    // Format is: something.vMAJOR, something.vMAJOR.MINOR or something.vMAJOR.MINOR.PATCH
     // Find the last ".v" followed by a digit
     let mut version_start = None;
     let bytes = last_segment.as_bytes();
@@ -111,23 +149,16 @@ fn extract_schema_version(schema_id: &str) -> Option<Version> {
     let version_start = version_start?;
     let version_part = &last_segment[version_start..];
 
-    // Parse version: MAJOR or MAJOR.MINOR
-    if let Some(dot_pos) = version_part.find('.') {
-        // Has minor version: MAJOR.MINOR
-        let major_str = &version_part[..dot_pos];
-        let minor_str = &version_part[dot_pos + 1..];
-
-        let major = major_str.parse::<u32>().ok()?;
-        let minor = minor_str.parse::<u32>().ok()?;
-        Some(Version {
-            major,
-            minor: Some(minor),
-        })
-    } else {
-        // Only major version
-        let major = version_part.parse::<u32>().ok()?;
-        Some(Version { major, minor: None })
-    }
+    // Split off an optional pre-release label: MAJOR[.MINOR[.PATCH]]-label
+    let (version_part, pre_release) = match version_part.split_once('-') {
+        Some((numbers, label)) if is_pre_release_label(label) => {
+            (numbers, Some(label.to_ascii_lowercase()))
+        }
+        Some(_) => return None,
+        None => (version_part, None),
+    };
+
+    parse_version_numbers(version_part, '.', pre_release)
 }
 
 /// Extract the parent schema ID from a `schema_id` (removes the last segment)
@@ -225,6 +256,173 @@ fn field_name_matches(field: &syn::Field, names: &[&str]) -> bool {
         .is_some_and(|name| names.contains(&name.to_string().as_str()))
 }
 
+/// A field whose schema is replaced by a string `enum` (from `#[gts(enum_values = "...")]`)
+struct EnumField {
+    /// Serialized property name
+    name: String,
+    values: Vec<String>,
+    /// Whether the field is an `Option<_>` (then `null` is also allowed)
+    optional: bool,
+}
+
+/// Schema overrides collected from field-level `#[gts(...)]` attributes
+#[derive(Default)]
+struct GtsFieldOverrides {
+    enum_fields: Vec<EnumField>,
+    /// Rust names of fields marked `#[gts(skip)]` (excluded from `properties = "*"`)
+    skipped_fields: Vec<String>,
+}
+
+/// Check if a type is `Option<_>`
+fn is_option_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(type_path)
+        if type_path.path.segments.last().is_some_and(|seg| seg.ident == "Option"))
+}
+
+/// Consume field-level `#[gts(...)]` attributes and apply them to the struct.
+///
+/// `#[gts(rename = "name")]` is translated into `#[serde(rename = "name")]` so that the
+/// generated schema (built by schemars) and the serialized instances use the same property name.
+/// In derive mode the struct can't be modified, so a matching `serde(rename)` must already exist.
+///
+/// Attributes that change the generated schema itself (e.g. `enum_values`) are returned as
+/// [`GtsFieldOverrides`] to be applied to the schemars output.
+fn apply_gts_field_attrs(
+    input: &mut syn::DeriveInput,
+    derive_mode: bool,
+) -> syn::Result<GtsFieldOverrides> {
+    let mut overrides = GtsFieldOverrides::default();
+    let Data::Struct(data_struct) = &mut input.data else {
+        return Ok(overrides);
+    };
+    let Fields::Named(fields) = &mut data_struct.fields else {
+        return Ok(overrides);
+    };
+
+    for field in &mut fields.named {
+        let mut rename: Option<LitStr> = None;
+        let mut enum_values: Option<LitStr> = None;
+        let mut skip = false;
+        let mut gts_attrs = Vec::new();
+        field.attrs.retain(|attr| {
+            if attr.path().is_ident("gts") {
+                gts_attrs.push(attr.clone());
+                false
+            } else {
+                true
+            }
+        });
+
+        for attr in &gts_attrs {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("enum_values") {
+                    enum_values = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "Unknown gts field attribute. Expected: rename, enum_values, or skip",
+                    ))
+                }
+            })?;
+        }
+
+        if skip && let Some(ident) = &field.ident {
+            overrides.skipped_fields.push(ident.to_string());
+        }
+
+        if let Some(rename) = rename {
+            match get_serde_rename(field) {
+                Some(existing) if existing == rename.value() => {}
+                Some(existing) => {
+                    return Err(syn::Error::new_spanned(
+                        &rename,
+                        format!(
+                            "struct_to_gts_schema: #[gts(rename = \"{}\")] conflicts with #[serde(rename = \"{existing}\")]",
+                            rename.value()
+                        ),
+                    ));
+                }
+                None if derive_mode => {
+                    return Err(syn::Error::new_spanned(
+                        &rename,
+                        format!(
+                            "derive(GtsSchema): #[gts(rename = \"{0}\")] requires #[serde(rename = \"{0}\")] on the same field",
+                            rename.value()
+                        ),
+                    ));
+                }
+                None => field
+                    .attrs
+                    .push(syn::parse_quote!(#[serde(rename = #rename)])),
+            }
+        }
+
+        if let Some(enum_values) = enum_values {
+            let values: Vec<String> = enum_values
+                .value()
+                .split(',')
+                .map(|v| v.trim().to_owned())
+                .filter(|v| !v.is_empty())
+                .collect();
+            if values.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &enum_values,
+                    "struct_to_gts_schema: enum_values must list at least one value (e.g., enum_values = \"A,B,C\")",
+                ));
+            }
+            let name = get_serde_rename(field)
+                .or_else(|| field.ident.as_ref().map(ToString::to_string))
+                .unwrap_or_default();
+            overrides.enum_fields.push(EnumField {
+                name,
+                values,
+                optional: is_option_type(&field.ty),
+            });
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Generate code that applies [`GtsFieldOverrides`] to a mutable `properties` JSON value
+fn field_overrides_code(overrides: &GtsFieldOverrides) -> proc_macro2::TokenStream {
+    if overrides.enum_fields.is_empty() {
+        return quote! {};
+    }
+
+    let enum_overrides = overrides.enum_fields.iter().map(|field| {
+        let name = &field.name;
+        let values = &field.values;
+        let enum_schema = if field.optional {
+            quote! { serde_json::json!({ "type": ["string", "null"], "enum": [#(#values,)* null] }) }
+        } else {
+            quote! { serde_json::json!({ "type": "string", "enum": [#(#values),*] }) }
+        };
+        quote! {
+            if let Some(field_schema) = props.get_mut(#name) {
+                let description = field_schema.get("description").cloned();
+                *field_schema = #enum_schema;
+                if let Some(description) = description {
+                    field_schema["description"] = description;
+                }
+            }
+        }
+    });
+
+    quote! {
+        // Replace enum-typed fields with a string `enum` schema (from #[gts(enum_values = "...")])
+        if let Some(props) = properties.as_object_mut() {
+            #(#enum_overrides)*
+        }
+    }
+}
+
 /// Validate base struct field requirements
 fn validate_base_struct_fields(
     input: &syn::DeriveInput,
@@ -290,25 +488,54 @@ fn validate_field_types(
     Ok(())
 }
 
-/// Validate that the struct name version suffix matches the `schema_id` version
-fn validate_version_match(struct_ident: &syn::Ident, schema_id: &str) -> syn::Result<()> {
+/// Suggest a struct name carrying the given version (e.g., `FooEventV1` + v1.0 -> `FooEventV1_0`)
+fn suggest_struct_name(struct_name: &str, version: &Version) -> String {
+    let base =
+        find_struct_version_start(struct_name).map_or(struct_name, |pos| &struct_name[..pos]);
+    format!("{base}{}", version.to_struct_suffix())
+}
+
+/// Suggest a `schema_id` whose last segment carries the given version
+/// (e.g., `gts.x.foo.bar.event.v1.0~` + V1 -> `gts.x.foo.bar.event.v1~`)
+fn suggest_schema_id(schema_id: &str, version: &Version) -> Option<String> {
+    let bytes = schema_id.as_bytes();
+    let version_pos = (0..bytes.len().saturating_sub(2))
+        .rev()
+        .find(|&i| bytes[i] == b'.' && bytes[i + 1] == b'v' && bytes[i + 2].is_ascii_digit())?;
+    Some(format!(
+        "{}.{}~",
+        &schema_id[..version_pos],
+        version.to_schema_version()
+    ))
+}
+
+/// Validate that the struct name version suffix matches the `schema_id` version.
+/// Returns the matched version.
+fn validate_version_match(struct_ident: &syn::Ident, schema_id: &str) -> syn::Result<Version> {
     let struct_name = struct_ident.to_string();
     let struct_version = extract_struct_version(&struct_name);
     let schema_version = extract_schema_version(schema_id);
 
     match (struct_version, schema_version) {
-        (Some(sv), Some(schv)) if sv != schv => Err(syn::Error::new_spanned(
-            struct_ident,
-            format!(
-                "struct_to_gts_schema: Version mismatch between struct name and schema_id. \
-                 Struct '{struct_name}' has version suffix '{}' but schema_id '{schema_id}' \
-                 has version '{}'. The versions must match exactly \
-                 (e.g., BaseEventV1 with v1~, or BaseEventV2_0 with v2.0~)",
-                sv.to_struct_suffix(),
-                schv.to_schema_version()
-            ),
-        )),
-        (Some(_), Some(_)) => Ok(()), // Versions match
+        (Some(sv), Some(schv)) if sv != schv => {
+            let schema_id_hint = suggest_schema_id(schema_id, &sv)
+                .map(|id| format!(" Alternatively, change schema_id to '{id}'."))
+                .unwrap_or_default();
+            Err(syn::Error::new_spanned(
+                struct_ident,
+                format!(
+                    "struct_to_gts_schema: Version mismatch between struct name and schema_id. \
+                     Struct '{struct_name}' has version suffix '{}' but schema_id '{schema_id}' \
+                     has version '{}'. The versions must match exactly \
+                     (e.g., BaseEventV1 with v1~, BaseEventV2_0 with v2.0~, or BaseEventV1Beta with v1-beta~). \
+                     Did you mean `{}`?{schema_id_hint}",
+                    sv.to_struct_suffix(),
+                    schv.to_schema_version(),
+                    suggest_struct_name(&struct_name, &schv)
+                ),
+            ))
+        }
+        (Some(_), Some(schv)) => Ok(schv), // Versions match
         (None, Some(schv)) => Err(syn::Error::new_spanned(
             struct_ident,
             format!(
@@ -346,8 +573,7 @@ fn has_derive(input: &syn::DeriveInput, trait_name: &str) -> bool {
             && attr
                 .meta
                 .require_list()
-                .map(|meta| meta.tokens.to_string().contains(trait_name))
-                .unwrap_or(false)
+                .is_ok_and(|meta| meta.tokens.to_string().contains(trait_name))
     })
 }
 
@@ -422,6 +648,28 @@ fn build_where_clause(
     }
 }
 
+/// Generate code that adds schema-level annotations (`deprecated`, `examples`) to a mutable `schema`
+fn schema_annotations_code(args: &GtsSchemaArgs) -> proc_macro2::TokenStream {
+    let deprecated = args.deprecated.as_ref().map(|message| {
+        quote! {
+            schema["deprecated"] = serde_json::json!(true);
+            schema["x-deprecated-message"] = serde_json::json!(#message);
+        }
+    });
+
+    let examples = args.examples.as_ref().map(|examples| {
+        quote! {
+            // Validated as a JSON array at compile time
+            schema["examples"] = serde_json::from_str(#examples).expect("examples");
+        }
+    });
+
+    quote! {
+        #deprecated
+        #examples
+    }
+}
+
 /// Represents the `base` attribute value for struct inheritance
 enum BaseAttr {
     /// This struct is a base type (no parent)
@@ -437,7 +685,13 @@ struct GtsSchemaArgs {
     schema_id: String,
     description: String,
     properties: String,
+    /// Span of the `properties` literal, for error reporting
+    properties_span: proc_macro2::Span,
     base: BaseAttr,
+    /// Optional deprecation message (`deprecated = "Use FooV2 instead"`)
+    deprecated: Option<String>,
+    /// Optional JSON array of schema examples, validated at parse time
+    examples: Option<String>,
 }
 
 impl Parse for GtsSchemaArgs {
@@ -445,8 +699,10 @@ impl Parse for GtsSchemaArgs {
         let mut dir_path: Option<String> = None;
         let mut schema_id: Option<String> = None;
         let mut description: Option<String> = None;
-        let mut properties: Option<String> = None;
+        let mut properties: Option<LitStr> = None;
         let mut base: Option<BaseAttr> = None;
+        let mut deprecated: Option<String> = None;
+        let mut examples: Option<String> = None;
 
         while !input.is_empty() {
             let key: syn::Ident = input.parse()?;
@@ -459,6 +715,16 @@ impl Parse for GtsSchemaArgs {
                 }
                 "schema_id" => {
                     let value: LitStr = input.parse()?;
+                    // GTS schema (type) IDs always end with '~'; instance IDs never do
+                    if !value.value().ends_with('~') {
+                        return Err(syn::Error::new_spanned(
+                            &value,
+                            format!(
+                                "struct_to_gts_schema: schema_id must end with '~' to identify a schema (e.g., \"{}~\")",
+                                value.value()
+                            ),
+                        ));
+                    }
                     schema_id = Some(value.value());
                 }
                 "description" => {
@@ -466,8 +732,29 @@ impl Parse for GtsSchemaArgs {
                     description = Some(value.value());
                 }
                 "properties" => {
+                    properties = Some(input.parse()?);
+                }
+                "deprecated" => {
                     let value: LitStr = input.parse()?;
-                    properties = Some(value.value());
+                    deprecated = Some(value.value());
+                }
+                "examples" => {
+                    let value: LitStr = input.parse()?;
+                    match serde_json::from_str::<serde_json::Value>(&value.value()) {
+                        Ok(serde_json::Value::Array(_)) => examples = Some(value.value()),
+                        Ok(_) => {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                "struct_to_gts_schema: examples must be a JSON array (e.g., examples = \"[{\\\"name\\\": \\\"Alice\\\"}]\")",
+                            ));
+                        }
+                        Err(err) => {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                format!("struct_to_gts_schema: examples is not valid JSON: {err}"),
+                            ));
+                        }
+                    }
                 }
                 "base" => {
                     // base can be: true (is a base type) or a struct name (parent struct)
@@ -496,7 +783,7 @@ impl Parse for GtsSchemaArgs {
                 _ => {
                     return Err(syn::Error::new_spanned(
                         key,
-                        "Unknown attribute. Expected: dir_path, schema_id, description, properties, or base",
+                        "Unknown attribute. Expected: dir_path, schema_id, description, properties, base, deprecated, or examples",
                     ));
                 }
             }
@@ -506,6 +793,9 @@ impl Parse for GtsSchemaArgs {
             }
         }
 
+        let properties =
+            properties.ok_or_else(|| input.error("Missing required attribute: properties"))?;
+
         Ok(GtsSchemaArgs {
             dir_path: dir_path
                 .ok_or_else(|| input.error("Missing required attribute: dir_path"))?,
@@ -513,10 +803,12 @@ impl Parse for GtsSchemaArgs {
                 .ok_or_else(|| input.error("Missing required attribute: schema_id"))?,
             description: description
                 .ok_or_else(|| input.error("Missing required attribute: description"))?,
-            properties: properties
-                .ok_or_else(|| input.error("Missing required attribute: properties"))?,
+            properties: properties.value(),
+            properties_span: properties.span(),
             base: base
                 .ok_or_else(|| input.error("Missing required attribute: base (use 'base = true' for base types or 'base = ParentStruct' for child types)"))?,
+            deprecated,
+            examples,
         })
     }
 }
@@ -530,9 +822,10 @@ impl Parse for GtsSchemaArgs {
 /// The macro validates your annotations at compile time, catching errors early:
 /// - ✅ All required attributes exist (`dir_path`, `schema_id`, `description`, `properties`)
 /// - ✅ Every property in `properties` exists as a field in the struct
+/// - ✅ `properties` is non-empty for structs with fields
 /// - ✅ Only structs with named fields are supported (no tuple/unit structs or enums)
 /// - ✅ Single generic parameter maximum (prevents inheritance ambiguity)
-/// - ✅ Valid GTS ID format enforcement
+/// - ✅ Valid GTS ID format enforcement (`schema_id` must end with `~`)
 /// - ✅ Zero runtime allocation for generated constants
 ///
 /// ## 2. Schema Generation
@@ -571,10 +864,25 @@ impl Parse for GtsSchemaArgs {
 ///   - **Automatic inheritance**: If the `schema_id` contains multiple segments separated by `~`, inheritance is automatically detected
 ///   - Example: `gts.x.core.events.type.v1~x.core.audit.event.v1~` inherits from `gts.x.core.events.type.v1~`
 /// * `description` - Human-readable description of the schema
-/// * `properties` - Comma-separated list of struct fields to include in the schema
+/// * `properties` - Comma-separated list of struct fields to include in the schema, or `"*"` for
+///   all named fields (except those marked `#[gts(skip)]`)
 /// * `base` - Explicit base/parent struct declaration (required):
 ///   - `base = true`: Marks this struct as a base type (must have single-segment `schema_id`)
 ///   - `base = ParentStruct`: Parent struct name (macro automatically uses `ParentStruct<()>`)
+/// * `deprecated` - Optional deprecation message. Adds `"deprecated": true` and
+///   `"x-deprecated-message"` to the schema and marks `gts_make_instance_id()` as `#[deprecated]`
+/// * `examples` - Optional JSON array of sample instances, embedded under the schema's `examples`
+///   key (checked at compile time to be a valid JSON array)
+///
+/// # Field Attributes
+///
+/// * `#[gts(rename = "name")]` - Use `name` as the property name in the generated schema's
+///   `properties` and `required` (applied as `serde(rename)`, so instances serialize the same way)
+/// * `#[gts(enum_values = "A,B,C")]` - Generate `{"type": "string", "enum": ["A", "B", "C"]}` for
+///   a field whose type is a Rust enum serialized as strings (`null` is also allowed for `Option`)
+/// * `#[gts(skip)]` - Exclude the field from `properties = "*"`
+///
+/// Field doc comments (`/// ...`) are used as the `description` of the corresponding property.
 ///
 /// # Memory Efficiency
 ///
@@ -607,24 +915,94 @@ impl Parse for GtsSchemaArgs {
 /// assert_eq!(instance_id.as_ref(), "gts.x.core.events.topic.v1~vendor.marketplace.orders.order_created.v1");
 /// ```
 #[proc_macro_attribute]
-#[allow(
-    clippy::too_many_lines,
-    clippy::missing_panics_doc,
-    clippy::cognitive_complexity
-)]
 pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as GtsSchemaArgs);
     let input = parse_macro_input!(item as DeriveInput);
 
+    expand_gts_schema(&args, input, false)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the GTS schema API for a struct.
+///
+/// This is the derive-style equivalent of [`macro@struct_to_gts_schema`]: the same arguments are
+/// given in a `#[gts(...)]` container attribute, and the same validation and runtime API are
+/// generated. Since a derive cannot modify the struct it is applied to, the struct must derive
+/// `serde::Serialize`, `serde::Deserialize` and `schemars::JsonSchema` itself, and field
+/// renames must be spelled as `#[serde(rename = "...")]`.
+///
+/// # Example
+///
+/// ```ignore
+/// use gts_macros::GtsSchema;
+///
+/// #[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema, GtsSchema)]
+/// #[gts(
+///     dir_path = "schemas",
+///     base = true,
+///     schema_id = "gts.x.core.events.topic.v1~",
+///     description = "Event broker topics",
+///     properties = "id,name"
+/// )]
+/// struct TopicV1 {
+///     id: gts::GtsInstanceId,
+///     name: String,
+/// }
+/// ```
+#[proc_macro_derive(GtsSchema, attributes(gts))]
+pub fn derive_gts_schema(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let mut container_attrs = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("gts"));
+    let Some(attr) = container_attrs.next() else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "derive(GtsSchema): Missing container attribute #[gts(dir_path = ..., schema_id = ..., description = ..., properties = ..., base = ...)]",
+        )
+        .into_compile_error()
+        .into();
+    };
+    if let Some(extra) = container_attrs.next() {
+        return syn::Error::new_spanned(
+            extra,
+            "derive(GtsSchema): Only one #[gts(...)] container attribute is allowed",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    attr.parse_args::<GtsSchemaArgs>()
+        .and_then(|args| expand_gts_schema(&args, input.clone(), true))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Shared expansion for [`macro@struct_to_gts_schema`] and [`derive@GtsSchema`].
+///
+/// In derive mode the struct itself is not re-emitted, so no derives, generic bounds or
+/// field attributes are added to it; only the generated impls are returned.
+#[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+fn expand_gts_schema(
+    args: &GtsSchemaArgs,
+    mut input: DeriveInput,
+    derive_mode: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    // Apply field-level #[gts(...)] attributes before any validation sees the fields
+    let field_overrides = apply_gts_field_attrs(&mut input, derive_mode)?;
+    let field_overrides_code = field_overrides_code(&field_overrides);
+    let schema_annotations_code = schema_annotations_code(args);
+
     // Prohibit multiple type generic parameters (GTS notation assumes nested segments)
     let generic_count = input.generics.type_params().count();
     if generic_count > 1 {
-        return syn::Error::new_spanned(
+        return Err(syn::Error::new_spanned(
             &input.ident,
             "struct_to_gts_schema: Multiple type generic parameters are not supported (GTS schemas assume nested segments)",
-        )
-        .to_compile_error()
-        .into();
+        ));
     }
 
     // base = true can have 0 or 1 generic field:
@@ -632,13 +1010,25 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
     // - 1 generic: Derived structs can extend via the generic field
     // (validation that base = ParentStruct requires parent to have 1 generic is done later via compile-time assertion)
 
-    // Parse properties list
-    let property_names: Vec<String> = args
-        .properties
-        .split(',')
-        .map(|s| s.trim().to_owned())
-        .filter(|s| !s.is_empty())
-        .collect();
+    // Parse properties list; "*" means all named fields except those marked #[gts(skip)]
+    let include_all_properties = args.properties.trim() == "*";
+    let property_names: Vec<String> = if include_all_properties {
+        match &input.data {
+            Data::Struct(data_struct) => data_struct
+                .fields
+                .iter()
+                .filter_map(|f| f.ident.as_ref().map(ToString::to_string))
+                .filter(|name| !field_overrides.skipped_fields.contains(name))
+                .collect(),
+            _ => Vec::new(),
+        }
+    } else {
+        args.properties
+            .split(',')
+            .map(|s| s.trim().to_owned())
+            .filter(|s| !s.is_empty())
+            .collect()
+    };
 
     // Extract struct fields for validation
     // Allow unit structs (no fields) for nested types that don't add new properties
@@ -648,38 +1038,41 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
             Fields::Unit => {
                 // Unit structs are allowed for nested types with empty properties
                 if !property_names.is_empty() {
-                    return syn::Error::new_spanned(
+                    return Err(syn::Error::new_spanned(
                         &input.ident,
                         "struct_to_gts_schema: Unit struct cannot have properties. \
                          Either add named fields or use properties = \"\"",
-                    )
-                    .to_compile_error()
-                    .into();
+                    ));
                 }
                 None // No fields to validate
             }
             Fields::Unnamed(_) => {
-                return syn::Error::new_spanned(
+                return Err(syn::Error::new_spanned(
                     &input.ident,
                     "struct_to_gts_schema: Tuple structs are not supported. \
                      Use a struct with named fields or a unit struct (for empty nested types)",
-                )
-                .to_compile_error()
-                .into();
+                ));
             }
         },
         _ => {
-            return syn::Error::new_spanned(
+            return Err(syn::Error::new_spanned(
                 &input.ident,
                 "struct_to_gts_schema: Only structs are supported",
-            )
-            .to_compile_error()
-            .into();
+            ));
         }
     };
 
     // Validate that all requested properties exist (only for structs with fields)
     if let Some(fields) = struct_fields {
+        // An empty list on a struct with fields silently generates an empty schema
+        if property_names.is_empty() && !fields.is_empty() {
+            return Err(syn::Error::new(
+                args.properties_span,
+                "struct_to_gts_schema: properties must list at least one field (e.g., properties = \"id,name\") \
+                 or use properties = \"*\" to include all fields",
+            ));
+        }
+
         let available_fields: Vec<String> = fields
             .iter()
             .filter_map(|f| f.ident.as_ref().map(ToString::to_string))
@@ -687,27 +1080,29 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
 
         for prop in &property_names {
             if !available_fields.contains(prop) {
-                return syn::Error::new_spanned(
+                return Err(syn::Error::new_spanned(
                     &input.ident,
                     format!(
                         "struct_to_gts_schema: Property '{prop}' not found in struct. Available fields: {available_fields:?}"
                     ),
-                )
-                .to_compile_error()
-                .into();
+                ));
+            }
+            if field_overrides.skipped_fields.contains(prop) {
+                return Err(syn::Error::new(
+                    args.properties_span,
+                    format!(
+                        "struct_to_gts_schema: Property '{prop}' is listed in properties but marked #[gts(skip)]"
+                    ),
+                ));
             }
         }
 
         // Validate base struct field requirements
-        if let Err(err) = validate_base_struct_fields(&input, fields, &args) {
-            return err.to_compile_error().into();
-        }
+        validate_base_struct_fields(&input, fields, args)?;
     }
 
     // Validate version match between struct name suffix and schema_id
-    if let Err(err) = validate_version_match(&input.ident, &args.schema_id) {
-        return err.to_compile_error().into();
-    }
+    let schema_version = validate_version_match(&input.ident, &args.schema_id)?;
 
     // Add GtsSchema bound to generic type parameters so that only valid GTS types
     // (those with struct_to_gts_schema applied, or ()) can be used as generic args.
@@ -722,9 +1117,7 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
     add_missing_derives(&mut modified_input);
 
     // Validate base attribute consistency with schema_id segments
-    if let Err(err) = validate_base_segments(&input, &args.base, &args.schema_id) {
-        return err.to_compile_error().into();
-    }
+    validate_base_segments(&input, &args.base, &args.schema_id)?;
     let expected_parent_schema_id = extract_parent_schema_id(&args.schema_id);
 
     // Build the schema output file path from dir_path + schema_id
@@ -732,10 +1125,45 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
     let dir_path = &args.dir_path;
     let schema_id = &args.schema_id;
     let description = &args.description;
-    let properties_str = &args.properties;
+    // Expand "*" so GTS_SCHEMA_PROPERTIES always lists the actual fields
+    let properties_str = if include_all_properties {
+        property_names.join(",")
+    } else {
+        args.properties.clone()
+    };
+
+    // SCHEMA_PROPERTIES uses the serialized names, as the generated schema's `properties` do
+    let schema_properties: Vec<String> = properties_str
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|name| {
+            struct_fields
+                .and_then(|fields| {
+                    fields
+                        .iter()
+                        .find(|f| f.ident.as_ref().is_some_and(|ident| ident == name))
+                })
+                .and_then(get_serde_rename)
+                .unwrap_or_else(|| name.to_owned())
+        })
+        .collect();
 
     let schema_file_path = format!("{dir_path}/{schema_id}.schema.json");
 
+    let version_major = schema_version.major;
+    let version_minor = if let Some(minor) = schema_version.minor {
+        quote! { Some(#minor) }
+    } else {
+        quote! { None }
+    };
+
+    // Deprecated schemas should not get new instances: warn on gts_make_instance_id()
+    let make_instance_id_deprecated = args
+        .deprecated
+        .as_ref()
+        .map(|message| quote! { #[deprecated(note = #message)] });
+
     // Extract generics to properly handle generic structs
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -945,14 +1373,20 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                 let mut properties = schema_val.get("properties").cloned().unwrap_or(serde_json::json!({}));
                 let required = schema_val.get("required").cloned().unwrap_or(serde_json::json!([]));
 
+                #field_overrides_code
+
                 // Replace the generic field with a simple {"type": "object"} placeholder
                 // The generic field should not be expanded, regardless of the concrete type parameter
                 if let Some(generic_field) = Self::GENERIC_FIELD {
                     if let Some(props) = properties.as_object_mut() {
-                        if props.contains_key(generic_field) {
-                            props.insert(generic_field.to_owned(), serde_json::json!({
+                        if let Some(field_schema) = props.get_mut(generic_field) {
+                            let description = field_schema.get("description").cloned();
+                            *field_schema = serde_json::json!({
                                 "type": "object"
-                            }));
+                            });
+                            if let Some(description) = description {
+                                field_schema["description"] = description;
+                            }
                         }
                     }
                 }
@@ -971,6 +1405,7 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                     if !required.as_array().map(|a| a.is_empty()).unwrap_or(true) {
                         schema["required"] = required;
                     }
+                    #schema_annotations_code
                     return schema;
                 }
 
@@ -986,10 +1421,11 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                 let innermost_generic_field = <#generic_ident as ::gts::GtsSchema>::GENERIC_FIELD;
 
                 // Wrap properties in the nesting path
-                let nested_properties = Self::wrap_in_nesting_path(&nesting_path, properties, required.clone(), innermost_generic_field);
+                let nested_properties = ::gts::wrap_in_nesting_path(&nesting_path, properties, required.clone(), innermost_generic_field);
 
                 // Child type - use allOf with $ref to parent
-                serde_json::json!({
+                #[allow(unused_mut)]
+                let mut schema = serde_json::json!({
                     "$id": format!("gts://{}", schema_id),
                     "$schema": "http://json-schema.org/draft-07/schema#",
                     "type": "object",
@@ -1000,7 +1436,9 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                             "properties": nested_properties
                         }
                     ]
-                })
+                });
+                #schema_annotations_code
+                schema
             }
         }
     } else {
@@ -1053,16 +1491,27 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                 let mut properties = schema_val.get("properties").cloned().unwrap_or_else(|| serde_json::json!({}));
                 let required = schema_val.get("required").cloned().unwrap_or_else(|| serde_json::json!([]));
 
+                #field_overrides_code
+
                 // Resolve internal $ref references to GtsInstanceId and GtsSchemaId at compile time
                 // This is needed for schemas validated directly (not through GtsStore)
                 // Runtime resolution in GtsStore::resolve_schema_refs provides additional coverage
+                // Field doc comments (emitted by schemars as "description") are kept on the resolved value
                 if let Some(props_obj) = properties.as_object_mut() {
                     for (_key, value) in props_obj.iter_mut() {
                         if let Some(ref_str) = value.get("$ref").and_then(|v| v.as_str()) {
-                            if ref_str == "#/$defs/GtsInstanceId" {
-                                *value = gts::GtsInstanceId::json_schema_value();
+                            let resolved = if ref_str == "#/$defs/GtsInstanceId" {
+                                Some(gts::GtsInstanceId::json_schema_value())
                             } else if ref_str == "#/$defs/GtsSchemaId" {
-                                *value = gts::GtsSchemaId::json_schema_value();
+                                Some(gts::GtsSchemaId::json_schema_value())
+                            } else {
+                                None
+                            };
+                            if let Some(mut resolved) = resolved {
+                                if let Some(description) = value.get("description") {
+                                    resolved["description"] = description.clone();
+                                }
+                                *value = resolved;
                             }
                         }
                     }
@@ -1081,6 +1530,7 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                     if !required.as_array().map(|a| a.is_empty()).unwrap_or(true) {
                         schema["required"] = required;
                     }
+                    #schema_annotations_code
                     return schema;
                 }
 
@@ -1093,8 +1543,9 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                     .expect("Parent struct must have a generic field for derived types to extend");
 
                 // Wrap properties in the parent's generic field path
-                let nested_properties = Self::wrap_in_nesting_path(&[field_name], properties, required, None);
-                serde_json::json!({
+                let nested_properties = ::gts::wrap_in_nesting_path(&[field_name], properties, required, None);
+                #[allow(unused_mut)]
+                let mut schema = serde_json::json!({
                     "$id": format!("gts://{}", schema_id),
                     "$schema": "http://json-schema.org/draft-07/schema#",
                     "type": "object",
@@ -1105,7 +1556,9 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
                             "properties": nested_properties
                         }
                     ]
-                })
+                });
+                #schema_annotations_code
+                schema
             }
         }
     };
@@ -1202,8 +1655,15 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
         quote! {}
     };
 
+    // A derive must not re-emit the struct; the user's own derives provide serde/schemars impls
+    let (struct_item, custom_serialize_impl) = if derive_mode {
+        (quote! {}, quote! {})
+    } else {
+        (quote! { #modified_input }, custom_serialize_impl)
+    };
+
     let expanded = quote! {
-        #modified_input
+        #struct_item
 
         // Compile-time assertion for base struct matching (if specified)
         #base_assertion
@@ -1253,6 +1713,7 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
             /// Generate a GTS instance ID by appending a segment to the schema ID.
             #[allow(dead_code)]
             #[must_use]
+            #make_instance_id_deprecated
             pub fn gts_make_instance_id(segment: &str) -> ::gts::GtsInstanceId {
                 ::gts::GtsInstanceId::new(#schema_id, segment)
             }
@@ -1262,6 +1723,13 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
         impl #impl_generics ::gts::GtsSchema for #struct_name #ty_generics #gts_schema_where_clause {
             const SCHEMA_ID: &'static str = #schema_id;
             const GENERIC_FIELD: Option<&'static str> = #generic_field_option;
+            const SCHEMA_PROPERTIES: &'static [&'static str] = &[#(#schema_properties),*];
+            const SCHEMA_VERSION_MAJOR: u32 = #version_major;
+            const SCHEMA_VERSION_MINOR: Option<u32> = #version_minor;
+
+            fn parent_schema_id() -> Option<&'static str> {
+                #base_schema_id_option
+            }
 
             fn gts_schema_with_refs() -> serde_json::Value {
                 Self::gts_schema_with_refs_allof()
@@ -1314,5 +1782,5 @@ pub fn struct_to_gts_schema(attr: TokenStream, item: TokenStream) -> TokenStream
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
@@ -0,0 +1,10 @@
+//! Test: derive(GtsSchema) without a #[gts(...)] container attribute
+
+use gts_macros::GtsSchema;
+
+#[derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema, GtsSchema)]
+pub struct UserV1 {
+    pub id: gts::GtsInstanceId,
+}
+
+fn main() {}
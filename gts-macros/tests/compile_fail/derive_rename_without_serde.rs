@@ -0,0 +1,19 @@
+//! Test: derive(GtsSchema) with #[gts(rename)] but no matching #[serde(rename)]
+
+use gts::gts::GtsSchemaId;
+use gts_macros::GtsSchema;
+
+#[derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema, GtsSchema)]
+#[gts(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = "type_name"
+)]
+pub struct UserV1 {
+    #[gts(rename = "type")]
+    pub type_name: GtsSchemaId,
+}
+
+fn main() {}
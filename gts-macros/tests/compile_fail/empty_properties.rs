@@ -0,0 +1,16 @@
+//! Test: Empty properties list on a struct with fields
+
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = " , "
+)]
+pub struct UserV1 {
+    pub id: gts::GtsInstanceId,
+}
+
+fn main() {}
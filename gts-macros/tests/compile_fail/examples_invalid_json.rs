@@ -0,0 +1,17 @@
+//! Test: examples attribute that is not valid JSON
+
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = "id",
+    examples = "[{name: Alice}]"
+)]
+pub struct UserV1 {
+    pub id: gts::GtsInstanceId,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test: examples attribute that is valid JSON but not an array
+
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = "id",
+    examples = r#"{"id": "gts.x.app.entities.user.v1~x.app.users.alice.v1"}"#
+)]
+pub struct UserV1 {
+    pub id: gts::GtsInstanceId,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//! Test: #[gts(rename)] conflicting with an existing #[serde(rename)]
+
+use gts::gts::GtsSchemaId;
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = "type_name"
+)]
+pub struct UserV1 {
+    #[serde(rename = "schema")]
+    #[gts(rename = "type")]
+    pub type_name: GtsSchemaId,
+}
+
+fn main() {}
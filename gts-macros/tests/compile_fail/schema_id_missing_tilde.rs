@@ -0,0 +1,16 @@
+//! Test: schema_id without the trailing '~'
+
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.type.v1",
+    description = "Base event type",
+    properties = "id"
+)]
+pub struct BaseEventV1 {
+    pub id: gts::GtsInstanceId,
+}
+
+fn main() {}
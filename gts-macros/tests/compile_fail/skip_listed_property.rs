@@ -0,0 +1,18 @@
+//! Test: A property listed explicitly but marked #[gts(skip)]
+
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.app.entities.user.v1~",
+    description = "User entity",
+    properties = "id,secret"
+)]
+pub struct UserV1 {
+    pub id: gts::GtsInstanceId,
+    #[gts(skip)]
+    pub secret: String,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Test: Version mismatch error suggests a corrected struct name and schema_id
+//! FooEventV1 should not work with v1.0~ schema
+
+use gts::GtsInstanceId;
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.foo.v1.0~",
+    description = "Foo event type",
+    properties = "id"
+)]
+pub struct FooEventV1 {
+    pub id: GtsInstanceId,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Test: Struct patch version doesn't match schema_id patch version
+//! BaseEventV1_0_1 should not work with v1.0.2~ schema
+
+use gts::GtsInstanceId;
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.type.v1.0.2~",
+    description = "Base event type",
+    properties = "id"
+)]
+pub struct BaseEventV1_0_1 {
+    pub id: GtsInstanceId,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Test: Struct pre-release suffix doesn't match schema_id version
+//! BaseEventV1Beta should not work with a stable v1~ schema
+
+use gts::GtsInstanceId;
+use gts_macros::struct_to_gts_schema;
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.type.v1~",
+    description = "Base event type",
+    properties = "id"
+)]
+pub struct BaseEventV1Beta {
+    pub id: GtsInstanceId,
+}
+
+fn main() {}
@@ -0,0 +1,169 @@
+//! Test: `derive(GtsSchema)` as an alternative to the `struct_to_gts_schema` attribute
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::str_to_string,
+    clippy::nonminimal_bool,
+    clippy::uninlined_format_args,
+    clippy::bool_assert_comparison
+)]
+
+use gts::gts::GtsSchemaId;
+use gts::{GtsInstanceId, GtsSchema};
+use gts_macros::{GtsSchema, struct_to_gts_schema};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/* ============================================================
+Derive-style hierarchy
+============================================================ */
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, GtsSchema)]
+#[gts(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.type.v1~",
+    description = "Base event type definition",
+    properties = "event_type,id,payload"
+)]
+pub struct DerivedBaseEventV1<P> {
+    #[serde(rename = "type")]
+    #[gts(rename = "type")]
+    pub event_type: GtsSchemaId,
+    pub id: Uuid,
+    pub payload: P,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, GtsSchema)]
+#[gts(
+    dir_path = "schemas",
+    base = DerivedBaseEventV1,
+    schema_id = "gts.x.core.events.type.v1~x.core.simple.event.v1~",
+    description = "Simple event payload with just a message",
+    properties = "message,severity"
+)]
+pub struct DerivedSimplePayloadV1 {
+    pub message: String,
+    pub severity: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, GtsSchema)]
+#[gts(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.entities.product.v1~",
+    description = "Product entity",
+    properties = "id,name"
+)]
+pub struct DerivedProductV1 {
+    pub id: GtsInstanceId,
+    pub name: String,
+}
+
+/* ============================================================
+Attribute-style equivalents for comparison
+============================================================ */
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.type.v1~",
+    description = "Base event type definition",
+    properties = "event_type,id,payload"
+)]
+#[derive(Debug)]
+pub struct AttrBaseEventV1<P> {
+    #[gts(rename = "type")]
+    pub event_type: GtsSchemaId,
+    pub id: Uuid,
+    pub payload: P,
+}
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = AttrBaseEventV1,
+    schema_id = "gts.x.core.events.type.v1~x.core.simple.event.v1~",
+    description = "Simple event payload with just a message",
+    properties = "message,severity"
+)]
+#[derive(Debug)]
+pub struct AttrSimplePayloadV1 {
+    pub message: String,
+    pub severity: u8,
+}
+
+/* ============================================================
+Tests
+============================================================ */
+
+#[test]
+fn test_derive_schema_ids() {
+    assert_eq!(
+        DerivedBaseEventV1::<()>::gts_schema_id().as_ref(),
+        "gts.x.core.events.type.v1~"
+    );
+    assert!(DerivedBaseEventV1::<()>::gts_base_schema_id().is_none());
+    assert_eq!(
+        DerivedSimplePayloadV1::gts_base_schema_id()
+            .unwrap()
+            .as_ref(),
+        "gts.x.core.events.type.v1~"
+    );
+    assert_eq!(
+        <DerivedBaseEventV1<()> as GtsSchema>::GENERIC_FIELD,
+        Some("payload")
+    );
+}
+
+#[test]
+fn test_derive_schema_matches_attribute_macro() {
+    // The derive must generate the same schemas as the attribute macro
+    assert_eq!(
+        DerivedBaseEventV1::<()>::gts_schema_with_refs_allof(),
+        AttrBaseEventV1::<()>::gts_schema_with_refs_allof()
+    );
+    assert_eq!(
+        DerivedSimplePayloadV1::gts_schema_with_refs_allof(),
+        AttrSimplePayloadV1::gts_schema_with_refs_allof()
+    );
+}
+
+#[test]
+fn test_derive_nested_schema() {
+    let schema = DerivedBaseEventV1::<DerivedSimplePayloadV1>::gts_schema_with_refs_allof();
+    assert_eq!(schema["$id"], "gts://gts.x.core.events.type.v1~");
+    assert_eq!(schema["properties"]["payload"]["type"], "object");
+    assert!(schema["properties"].get("type").is_some());
+
+    let child = DerivedSimplePayloadV1::gts_schema_with_refs_allof();
+    assert_eq!(
+        child["allOf"][0]["$ref"],
+        "gts://gts.x.core.events.type.v1~"
+    );
+    assert!(
+        child["allOf"][1]["properties"]["payload"]["properties"]
+            .get("message")
+            .is_some()
+    );
+}
+
+#[test]
+fn test_derive_instance_api() {
+    let product = DerivedProductV1 {
+        id: DerivedProductV1::gts_make_instance_id("x.test.entities.widget.v1"),
+        name: "Widget".to_string(),
+    };
+    assert_eq!(
+        product.id.as_ref(),
+        "gts.x.test.entities.product.v1~x.test.entities.widget.v1"
+    );
+
+    let json = product.gts_instance_json();
+    assert_eq!(json["name"], "Widget");
+
+    let schema = DerivedProductV1::gts_schema_with_refs_allof();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    assert!(validator.is_valid(&json));
+}
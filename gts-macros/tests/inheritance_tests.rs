@@ -1545,6 +1545,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nesting_path() {
+        assert_eq!(
+            BaseEventV1::<AuditPayloadV1<PlaceOrderDataV1>>::nesting_path(),
+            vec!["payload", "data"]
+        );
+        assert_eq!(
+            BaseEventV1::<SimplePayloadV1>::nesting_path(),
+            vec!["payload"]
+        );
+        assert!(BaseEventV1::<()>::nesting_path().is_empty());
+        assert!(PlaceOrderDataV1::nesting_path().is_empty());
+    }
+
+    #[test]
+    fn test_parent_schema_id() {
+        fn parent_of<T: GtsSchema>() -> Option<&'static str> {
+            T::parent_schema_id()
+        }
+
+        assert_eq!(BaseEventV1::<()>::parent_schema_id(), None);
+        assert_eq!(
+            AuditPayloadV1::<()>::parent_schema_id(),
+            Some("gts.x.core.events.type.v1~")
+        );
+        assert_eq!(
+            PlaceOrderDataV1::parent_schema_id(),
+            Some("gts.x.core.events.type.v1~x.core.audit.event.v1~")
+        );
+
+        assert_eq!(
+            parent_of::<SimplePayloadV1>(),
+            Some("gts.x.core.events.type.v1~")
+        );
+    }
+
     #[test]
     fn test_two_level_inheritance_field_path() {
         // Register schemas for validation
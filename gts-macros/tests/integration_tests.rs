@@ -55,6 +55,65 @@ pub struct ProductV1 {
     pub warehouse_location: String,
 }
 
+/// Order status, serialized as a plain string
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub enum OrderStatus {
+    Pending,
+    Shipped,
+    Delivered,
+}
+
+/// Order entity for testing enum fields
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.entities.order.v1~",
+    description = "Order entity with an enum status",
+    properties = "id,status,previous_status"
+)]
+pub struct OrderV1 {
+    pub id: GtsInstanceId,
+    /// Current order status
+    #[gts(enum_values = "Pending,Shipped,Delivered")]
+    pub status: OrderStatus,
+    #[gts(enum_values = "Pending,Shipped,Delivered")]
+    pub previous_status: Option<OrderStatus>,
+}
+
+/// Superseded product version for testing the `deprecated` attribute
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.entities.legacy_product.v1~",
+    description = "Legacy product entity",
+    properties = "id,name",
+    deprecated = "Use ProductV1 instead",
+    examples = r#"[{"id": "gts.x.test.entities.legacy_product.v1~x.test.entities.old.v1", "name": "Old"}]"#
+)]
+pub struct LegacyProductV1 {
+    pub id: GtsInstanceId,
+    pub name: String,
+}
+
+/// Customer entity listing all fields with `properties = "*"`
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.entities.customer.v1~",
+    description = "Customer entity with all fields as properties",
+    properties = "*"
+)]
+pub struct CustomerV1 {
+    pub id: GtsInstanceId,
+    pub name: String,
+    pub email: Option<String>,
+    #[gts(skip)]
+    pub internal_score: u32,
+}
+
 // =============================================================================
 // Tests for 3.a) GTS_SCHEMA_JSON - JSON Schema with proper $id
 // =============================================================================
@@ -117,6 +176,35 @@ fn test_schema_json_contains_only_specified_properties() {
     assert!(product_props.contains_key("warehouse_location"));
 }
 
+#[test]
+fn test_schema_json_field_descriptions_from_doc_comments() {
+    let topic_schema: serde_json::Value =
+        serde_json::from_str(&EventTopicV1::gts_schema_with_refs_as_string()).unwrap();
+    let topic_props = &topic_schema["properties"];
+
+    // Field doc comments become property descriptions
+    assert_eq!(topic_props["name"]["description"], "Topic name");
+    assert_eq!(
+        topic_props["retention"]["description"],
+        "How long events are retained (ISO-8601 duration, e.g., P30D)"
+    );
+    // Resolved GtsInstanceId fields keep their doc comment too
+    assert_eq!(
+        topic_props["id"]["description"],
+        "Identifier for the topic/stream in GTS notation"
+    );
+    assert_eq!(topic_props["id"]["format"], "gts-instance-id");
+
+    // Fields without doc comments have no description
+    let product_schema: serde_json::Value =
+        serde_json::from_str(&ProductV1::gts_schema_with_refs_as_string()).unwrap();
+    assert!(
+        product_schema["properties"]["name"]
+            .get("description")
+            .is_none()
+    );
+}
+
 #[test]
 fn test_schema_json_is_valid_json() {
     // Verify the schema JSON can be parsed
@@ -253,6 +341,22 @@ fn test_properties_constant() {
     );
 }
 
+#[test]
+fn test_properties_wildcard_constant() {
+    // "*" expands to all named fields except those marked #[gts(skip)]
+    assert_eq!(CustomerV1::GTS_SCHEMA_PROPERTIES, "id,name,email");
+
+    let customer = CustomerV1 {
+        id: CustomerV1::gts_make_instance_id("x.test.entities.alice.v1"),
+        name: "Alice".to_owned(),
+        email: None,
+        internal_score: 7,
+    };
+    let schema = CustomerV1::gts_schema_with_refs_allof();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    assert!(validator.is_valid(&customer.gts_instance_json()));
+}
+
 // =============================================================================
 // Tests for serialization (struct still works normally)
 // =============================================================================
@@ -324,6 +428,89 @@ fn test_event_topic_instance_validates_against_schema() {
     }
 }
 
+#[test]
+fn test_deprecated_schema() {
+    let schema = LegacyProductV1::gts_schema_with_refs_allof();
+    assert_eq!(schema["deprecated"], true);
+    assert_eq!(schema["x-deprecated-message"], "Use ProductV1 instead");
+
+    // Non-deprecated schemas carry no deprecation markers
+    let schema = ProductV1::gts_schema_with_refs_allof();
+    assert!(schema.get("deprecated").is_none());
+    assert!(schema.get("x-deprecated-message").is_none());
+}
+
+#[test]
+fn test_schema_examples() {
+    let schema = LegacyProductV1::gts_schema_with_refs_allof();
+    let examples = schema["examples"].as_array().unwrap();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0]["name"], "Old");
+
+    // Examples are valid instances of the schema
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    assert!(validator.is_valid(&examples[0]));
+
+    // No examples attribute, no examples key
+    assert!(
+        ProductV1::gts_schema_with_refs_allof()
+            .get("examples")
+            .is_none()
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_schema_instance_id() {
+    // gts_make_instance_id() is #[deprecated] but still works
+    let id = LegacyProductV1::gts_make_instance_id("x.test.entities.old.v1");
+    assert_eq!(
+        id.as_ref(),
+        "gts.x.test.entities.legacy_product.v1~x.test.entities.old.v1"
+    );
+}
+
+#[test]
+fn test_enum_field_schema() {
+    let schema = OrderV1::gts_schema_with_refs_allof();
+    let props = &schema["properties"];
+
+    assert_eq!(props["status"]["type"], "string");
+    assert_eq!(
+        props["status"]["enum"],
+        serde_json::json!(["Pending", "Shipped", "Delivered"])
+    );
+    assert_eq!(props["status"]["description"], "Current order status");
+    assert!(props["status"].get("$ref").is_none());
+
+    // Option<Enum> also accepts null
+    assert_eq!(
+        props["previous_status"]["type"],
+        serde_json::json!(["string", "null"])
+    );
+    assert_eq!(
+        props["previous_status"]["enum"],
+        serde_json::json!(["Pending", "Shipped", "Delivered", null])
+    );
+}
+
+#[test]
+fn test_enum_field_instance_validates_against_schema() {
+    let order = OrderV1 {
+        id: OrderV1::gts_make_instance_id("x.test.entities.order_1.v1"),
+        status: OrderStatus::Shipped,
+        previous_status: None,
+    };
+    let schema = OrderV1::gts_schema_with_refs_allof();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    assert!(validator.is_valid(&order.gts_instance_json()));
+
+    let mut invalid = order.gts_instance_json();
+    invalid["status"] = serde_json::json!("Lost");
+    assert!(!validator.is_valid(&invalid));
+}
+
 #[test]
 fn test_product_instance_validates_against_schema() {
     let product = ProductV1 {
@@ -1104,6 +1291,45 @@ pub struct ComplexMinorV2_5 {
     pub data: String,
 }
 
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.versioned.preview.v1-beta~",
+    description = "Test struct with a pre-release version",
+    properties = "id,value"
+)]
+pub struct PreviewVersionV1Beta {
+    pub id: GtsInstanceId,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.versioned.candidate.v2.1-rc~",
+    description = "Test struct with a pre-release minor version",
+    properties = "id,value"
+)]
+pub struct CandidateVersionV2_1Rc {
+    pub id: GtsInstanceId,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.test.versioned.patched.v1.2.3~",
+    description = "Test struct with a patch version",
+    properties = "id,value"
+)]
+pub struct PatchVersionV1_2_3 {
+    pub id: GtsInstanceId,
+    pub value: String,
+}
+
 #[test]
 fn test_version_with_underscore_v1_0() {
     // Test that struct with V1_0 suffix works with v1.0~ schema_id
@@ -1204,3 +1430,85 @@ fn test_base_true_single_segment_instance_id_generation() {
         "gts.x.test.single.segment.v1~test.instance.v1"
     );
 }
+
+#[test]
+fn test_validate_value() {
+    let valid = serde_json::json!({
+        "id": "gts.x.test.entities.product.v1~vendor.package.sku.abc.v1",
+        "name": "Widget",
+        "price": 9.99,
+        "in_stock": true,
+        "warehouse_location": "A1"
+    });
+    assert_eq!(ProductV1::validate_value(&valid), Ok(()));
+
+    let invalid = serde_json::json!({
+        "id": "gts.x.test.entities.product.v1~vendor.package.sku.abc.v1",
+        "name": 42,
+        "price": "free",
+        "in_stock": true,
+        "warehouse_location": "A1"
+    });
+    let errors = ProductV1::validate_value(&invalid).unwrap_err();
+    assert_eq!(errors.len(), 2, "errors: {:?}", errors);
+}
+
+#[test]
+fn test_register_in_store() {
+    let mut store = gts::GtsStore::new(None);
+    ProductV1::register_in_store(&mut store).unwrap();
+
+    let schema = store
+        .get_schema_content("gts.x.test.entities.product.v1~")
+        .unwrap();
+    assert_eq!(schema, ProductV1::gts_schema_with_refs());
+}
+
+#[test]
+fn test_schema_properties_const() {
+    assert_eq!(
+        ProductV1::SCHEMA_PROPERTIES,
+        &["id", "name", "price", "description", "in_stock"]
+    );
+    // "*" expands to all fields except the skipped ones
+    assert_eq!(CustomerV1::SCHEMA_PROPERTIES, &["id", "name", "email"]);
+}
+
+#[test]
+fn test_schema_version_consts() {
+    assert_eq!(ProductV1::SCHEMA_VERSION_MAJOR, 1);
+    assert_eq!(ProductV1::SCHEMA_VERSION_MINOR, None);
+    assert_eq!(MinorVersionV1_0::SCHEMA_VERSION_MAJOR, 1);
+    assert_eq!(MinorVersionV1_0::SCHEMA_VERSION_MINOR, Some(0));
+}
+
+#[test]
+fn test_version_with_pre_release_suffix() {
+    assert_eq!(
+        PreviewVersionV1Beta::SCHEMA_ID,
+        "gts.x.test.versioned.preview.v1-beta~"
+    );
+    assert_eq!(PreviewVersionV1Beta::SCHEMA_VERSION_MAJOR, 1);
+    assert_eq!(PreviewVersionV1Beta::SCHEMA_VERSION_MINOR, None);
+
+    assert_eq!(
+        CandidateVersionV2_1Rc::SCHEMA_ID,
+        "gts.x.test.versioned.candidate.v2.1-rc~"
+    );
+    assert_eq!(CandidateVersionV2_1Rc::SCHEMA_VERSION_MAJOR, 2);
+    assert_eq!(CandidateVersionV2_1Rc::SCHEMA_VERSION_MINOR, Some(1));
+}
+
+#[test]
+fn test_version_with_patch_number() {
+    assert_eq!(
+        PatchVersionV1_2_3::SCHEMA_ID,
+        "gts.x.test.versioned.patched.v1.2.3~"
+    );
+    assert_eq!(PatchVersionV1_2_3::SCHEMA_VERSION_MAJOR, 1);
+    assert_eq!(PatchVersionV1_2_3::SCHEMA_VERSION_MINOR, Some(2));
+
+    let id = PatchVersionV1_2_3::gts_make_instance_id("x.test.versioned.item.v1");
+    let parsed = gts::GtsID::new(id.as_ref()).unwrap();
+    assert_eq!(parsed.gts_id_segments[0].ver_patch, Some(3));
+}
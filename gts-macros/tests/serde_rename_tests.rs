@@ -68,6 +68,25 @@ pub struct BaseEventV1SchemaRenameV1<P> {
     pub payload: P,
 }
 
+/* ============================================================
+GTS rename tests - type_name field with gts(rename = "type")
+============================================================ */
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = true,
+    schema_id = "gts.x.core.events.gts_rename.v1~",
+    description = "Base event type with gts(rename = \"type\")",
+    properties = "type_name,id,payload"
+)]
+#[derive(Debug)]
+pub struct BaseEventV1GtsRenameV1<P> {
+    #[gts(rename = "type")]
+    pub type_name: GtsSchemaId,
+    pub id: Uuid,
+    pub payload: P,
+}
+
 /* ============================================================
 Tests
 ============================================================ */
@@ -203,4 +222,64 @@ mod tests {
         assert!(parsed.get("event_type").is_none());
         assert_eq!(parsed["schema"], "gts.x.core.events.schema.v1~");
     }
+
+    #[test]
+    fn test_gts_rename_schema_properties() {
+        use gts::GtsSchema;
+
+        let schema = BaseEventV1GtsRenameV1::<()>::gts_schema_with_refs_allof();
+
+        // The property should appear as "type" (not "type_name") in properties and required
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("type"));
+        assert!(!properties.contains_key("type_name"));
+
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(required.contains(&"type"));
+        assert!(!required.contains(&"type_name"));
+    }
+
+    #[test]
+    fn test_gts_rename_serialization() {
+        let event = BaseEventV1GtsRenameV1::<()> {
+            type_name: GtsSchemaId::new("gts.x.core.events.gts_rename.v1~"),
+            id: Uuid::new_v4(),
+            payload: (),
+        };
+
+        let parsed = event.gts_instance_json();
+
+        // Instances use the same name as the schema property
+        assert_eq!(parsed["type"], "gts.x.core.events.gts_rename.v1~");
+        assert!(parsed.get("type_name").is_none());
+    }
+
+    #[test]
+    fn test_schema_properties_use_renamed_names() {
+        use gts::GtsSchema;
+
+        fn assert_matches_schema<T: GtsSchema>() {
+            let schema = T::gts_schema_with_refs();
+            let mut keys: Vec<&str> = schema["properties"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect();
+            keys.sort_unstable();
+            let mut consts = T::SCHEMA_PROPERTIES.to_vec();
+            consts.sort_unstable();
+            assert_eq!(consts, keys);
+        }
+
+        assert_matches_schema::<BaseEventV1SerdeRenameV1<()>>();
+        assert_matches_schema::<BaseEventV1GtsRenameV1<()>>();
+        assert!(BaseEventV1SerdeRenameV1::<()>::SCHEMA_PROPERTIES.contains(&"type"));
+        assert!(BaseEventV1GtsRenameV1::<()>::SCHEMA_PROPERTIES.contains(&"type"));
+    }
 }
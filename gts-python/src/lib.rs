@@ -0,0 +1,213 @@
+//! Python bindings for [`GtsOps`], built with `PyO3`.
+//!
+//! The module is importable as `gts_python` and exposes `GtsOps` (the [`PyGtsOps`]
+//! class) and the `GtsError` exception. Results are returned as plain `dict`/`list`
+//! values with the same shape as the CLI's JSON output; failed operations raise
+//! `GtsError`.
+
+use gts::{GtsOps, StoreError};
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::Serialize;
+use serde_json::Value;
+
+create_exception!(gts_python, GtsError, PyException);
+
+/// Converts a serializable result into Python objects through the `json` module.
+fn to_py<'py, T: Serialize>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    let json = serde_json::to_string(value).map_err(|e| GtsError::new_err(e.to_string()))?;
+    py.import("json")?.call_method1("loads", (json,))
+}
+
+/// Converts a Python object into JSON through the `json` module.
+fn from_py(value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let json: String = value
+        .py()
+        .import("json")?
+        .call_method1("dumps", (value,))?
+        .extract()?;
+    serde_json::from_str(&json).map_err(|e| GtsError::new_err(e.to_string()))
+}
+
+/// In-memory GTS operations exposed to Python as `GtsOps`.
+#[pyclass(name = "GtsOps", module = "gts_python", unsendable)]
+pub struct PyGtsOps {
+    ops: GtsOps,
+}
+
+#[pymethods]
+impl PyGtsOps {
+    /// Create a store, optionally loading entities from `path` (files or directories).
+    #[new]
+    #[pyo3(signature = (path = None, config = None))]
+    fn new(path: Option<Vec<String>>, config: Option<String>) -> Self {
+        PyGtsOps {
+            ops: GtsOps::new(path, config, 0),
+        }
+    }
+
+    /// Validate a GTS ID; returns `{"id", "valid", "error", ...}`.
+    #[staticmethod]
+    fn validate_id<'py>(py: Python<'py>, id: &str) -> PyResult<Bound<'py, PyAny>> {
+        to_py(py, &GtsOps::validate_id(id))
+    }
+
+    /// Validate a registered instance against its schema; returns `{"id", "ok", "error"}`.
+    ///
+    /// A failed validation is reported with `ok = False`; an unknown instance or
+    /// schema raises `GtsError`.
+    fn validate_instance<'py>(&mut self, py: Python<'py>, id: &str) -> PyResult<Bound<'py, PyAny>> {
+        match self.ops.store.validate_instance(id) {
+            Ok(()) | Err(StoreError::ValidationError(_)) => {
+                to_py(py, &self.ops.validate_instance(id))
+            }
+            Err(e) => Err(GtsError::new_err(e.to_string())),
+        }
+    }
+
+    /// Query entities by ID pattern and filters; returns the list of matching contents.
+    #[pyo3(signature = (expr, limit = 100))]
+    fn query<'py>(&self, py: Python<'py>, expr: &str, limit: usize) -> PyResult<Bound<'py, PyAny>> {
+        let result = self.ops.query(expr, limit);
+        if !result.error.is_empty() {
+            return Err(GtsError::new_err(result.error));
+        }
+        to_py(py, &result.results)
+    }
+
+    /// Register an entity (schema or instance); returns `{"ok", "id", "schema_id", "is_schema"}`.
+    #[pyo3(signature = (content, validate = false))]
+    fn add_entity<'py>(
+        &mut self,
+        py: Python<'py>,
+        content: &Bound<'py, PyDict>,
+        validate: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let result = self.ops.add_entity(&from_py(content.as_any())?, validate);
+        if !result.ok {
+            return Err(GtsError::new_err(result.error));
+        }
+        to_py(py, &result)
+    }
+
+    /// Cast an instance to another version of its schema; returns the cast report.
+    fn cast<'py>(
+        &mut self,
+        py: Python<'py>,
+        from_id: &str,
+        to_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let result = self.ops.cast(from_id, to_id);
+        if let Some(error) = result.error {
+            return Err(GtsError::new_err(error));
+        }
+        to_py(py, &result)
+    }
+}
+
+#[pymodule]
+fn gts_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyGtsOps>()?;
+    m.add("GtsError", m.py().get_type::<GtsError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyList;
+
+    const SCHEMA_ID: &str = "gts.x.test.py.user.v1~";
+    const INSTANCE_ID: &str = "gts.x.test.py.user.v1~x.test.py.alice.v1";
+
+    fn dict<'py>(py: Python<'py>, json: &str) -> Bound<'py, PyDict> {
+        py.import("json")
+            .unwrap()
+            .call_method1("loads", (json,))
+            .unwrap()
+            .cast_into::<PyDict>()
+            .unwrap()
+    }
+
+    fn create_ops(py: Python<'_>) -> Bound<'_, PyAny> {
+        let ops = py.get_type::<PyGtsOps>().call0().unwrap();
+        let schema = format!(
+            r#"{{"$id": "gts://{SCHEMA_ID}", "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object", "required": ["name"], "properties": {{"name": {{"type": "string"}}}}}}"#
+        );
+        ops.call_method1("add_entity", (dict(py, &schema),))
+            .unwrap();
+        ops.call_method1(
+            "add_entity",
+            (dict(
+                py,
+                &format!(r#"{{"id": "{INSTANCE_ID}", "name": "Alice"}}"#),
+            ),),
+        )
+        .unwrap();
+        ops
+    }
+
+    #[test]
+    fn test_validate_id_returns_dict() {
+        Python::attach(|py| {
+            let result = py
+                .get_type::<PyGtsOps>()
+                .call_method1("validate_id", (SCHEMA_ID,))
+                .unwrap();
+            let result = result.cast::<PyDict>().unwrap();
+            assert!(
+                result
+                    .get_item("valid")
+                    .unwrap()
+                    .unwrap()
+                    .extract::<bool>()
+                    .unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn test_add_entity_query_and_validate_instance() {
+        Python::attach(|py| {
+            let ops = create_ops(py);
+
+            let results = ops
+                .call_method1("query", ("gts.x.test.py.user.*",))
+                .unwrap();
+            assert_eq!(results.cast::<PyList>().unwrap().len(), 2);
+
+            let result = ops
+                .call_method1("validate_instance", (INSTANCE_ID,))
+                .unwrap();
+            let ok: bool = result.get_item("ok").unwrap().extract().unwrap();
+            assert!(ok);
+        });
+    }
+
+    #[test]
+    fn test_errors_raise_gts_error() {
+        Python::attach(|py| {
+            let ops = create_ops(py);
+
+            let err = ops
+                .call_method1("add_entity", (dict(py, r#"{"name": "no id"}"#),))
+                .unwrap_err();
+            assert!(err.is_instance_of::<GtsError>(py));
+
+            let err = ops
+                .call_method1(
+                    "validate_instance",
+                    ("gts.x.test.py.user.v1~x.test.py.bob.v1",),
+                )
+                .unwrap_err();
+            assert!(err.is_instance_of::<GtsError>(py));
+
+            let err = ops.call_method1("query", ("not-a-gts-id",)).unwrap_err();
+            assert!(err.is_instance_of::<GtsError>(py));
+        });
+    }
+}
@@ -0,0 +1,298 @@
+//! Axum JSON API exposing [`GtsOps`].
+//!
+//! [`create_router`] mounts the routes on a shared [`GtsOps`]; [`serve`] binds and runs
+//! them. This is the same API `gts server` serves. Every handler deserializes its
+//! request, calls the matching `GtsOps` method and returns the result as JSON:
+//!
+//! | Route                          | `GtsOps` method          |
+//! |--------------------------------|--------------------------|
+//! | `GET /entities`                | `get_entities`           |
+//! | `POST /entities`               | `add_entity`             |
+//! | `GET /entities/{id}`           | `get_entity`             |
+//! | `POST /entities/bulk`          | `add_entities`           |
+//! | `POST /schemas`                | `add_schema`             |
+//! | `GET /validate-id`             | `validate_id`            |
+//! | `POST /validate/id`            | `validate_id`            |
+//! | `POST /validate-instance`      | `validate_instance`      |
+//! | `POST /validate/schema`        | `validate_schema`        |
+//! | `POST /extract-id`             | `extract_id`             |
+//! | `GET /parse-id`                | `parse_id`               |
+//! | `GET /match-id-pattern`        | `match_id_pattern`       |
+//! | `GET /uuid`                    | `uuid`                   |
+//! | `GET /resolve-relationships`   | `schema_graph`           |
+//! | `GET /compatibility`           | `compatibility`          |
+//! | `POST /cast`                   | `cast`                   |
+//! | `GET /query`                   | `query`                  |
+//! | `GET /attr`                    | `attr`                   |
+//!
+//! `GET` routes take their arguments as query parameters and `POST` routes as a JSON
+//! body. Only `POST /entities` reports a failed result through its status code (422).
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use gts::GtsOps;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub ops: Arc<Mutex<GtsOps>>,
+}
+
+impl AppState {
+    #[must_use]
+    pub fn new(ops: GtsOps) -> Self {
+        Self {
+            ops: Arc::new(Mutex::new(ops)),
+        }
+    }
+}
+
+/// Builds the API router over `state`.
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        .route("/entities", get(get_entities).post(add_entity))
+        .route("/entities/{gts_id}", get(get_entity))
+        .route("/entities/bulk", post(add_entities))
+        .route("/schemas", post(add_schema))
+        .route("/validate-id", get(validate_id))
+        .route("/validate/id", post(validate_id_body))
+        .route("/validate-instance", post(validate_instance))
+        .route("/validate/schema", post(validate_schema))
+        .route("/extract-id", post(extract_id))
+        .route("/parse-id", get(parse_id))
+        .route("/match-id-pattern", get(match_id_pattern))
+        .route("/uuid", get(id_to_uuid))
+        .route("/resolve-relationships", get(schema_graph))
+        .route("/compatibility", get(compatibility))
+        .route("/cast", post(cast))
+        .route("/query", get(query))
+        .route("/attr", get(attr))
+        .with_state(state)
+}
+
+/// Serves the API for `ops` on `addr` until the server stops.
+///
+/// # Errors
+/// Returns an error if binding to `addr` fails or the server stops with an error.
+pub async fn serve(ops: GtsOps, addr: &str) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!("GTS API listening on {addr}");
+    axum::serve(listener, create_router(AppState::new(ops))).await
+}
+
+#[derive(Deserialize)]
+struct GtsIdQuery {
+    gts_id: String,
+}
+
+#[derive(Deserialize)]
+struct MatchIdQuery {
+    candidate: String,
+    pattern: String,
+}
+
+#[derive(Deserialize)]
+struct CompatibilityQuery {
+    old_schema_id: String,
+    new_schema_id: String,
+}
+
+#[derive(Deserialize)]
+struct QueryParams {
+    expr: String,
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Deserialize)]
+struct AttrQuery {
+    gts_with_path: String,
+}
+
+#[derive(Deserialize)]
+struct LimitQuery {
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Deserialize)]
+struct AddEntityQuery {
+    #[serde(default)]
+    validate: bool,
+}
+
+fn default_limit() -> usize {
+    100
+}
+
+#[derive(Deserialize)]
+struct SchemaRegister {
+    type_id: String,
+    schema: Value,
+}
+
+#[derive(Deserialize)]
+struct CastRequest {
+    instance_id: String,
+    to_schema_id: String,
+}
+
+#[derive(Deserialize)]
+struct ValidateInstanceRequest {
+    instance_id: String,
+}
+
+#[derive(Deserialize)]
+struct ValidateSchemaRequest {
+    schema_id: String,
+}
+
+/// Locks the shared ops, or builds the 500 response for a poisoned lock.
+fn lock_ops(state: &AppState) -> Result<MutexGuard<'_, GtsOps>, impl IntoResponse + use<>> {
+    state.ops.lock().map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({"error": "Server state corrupted"})),
+        )
+    })
+}
+
+async fn get_entities(State(state): State<AppState>, Query(params): Query<LimitQuery>) -> Response {
+    match lock_ops(&state) {
+        Ok(ops) => Json(ops.get_entities(params.limit)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn get_entity(State(state): State<AppState>, Path(gts_id): Path<String>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.get_entity(&gts_id)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn add_entity(
+    State(state): State<AppState>,
+    Query(params): Query<AddEntityQuery>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut ops = match lock_ops(&state) {
+        Ok(ops) => ops,
+        Err(response) => return response.into_response(),
+    };
+    let result = ops.add_entity(&body, params.validate);
+    let status = if result.ok {
+        StatusCode::OK
+    } else {
+        StatusCode::UNPROCESSABLE_ENTITY
+    };
+    (status, Json(result)).into_response()
+}
+
+async fn add_entities(State(state): State<AppState>, Json(body): Json<Vec<Value>>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.add_entities(&body)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn add_schema(State(state): State<AppState>, Json(body): Json<SchemaRegister>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.add_schema(body.type_id, &body.schema)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn validate_id(Query(params): Query<GtsIdQuery>) -> Response {
+    Json(GtsOps::validate_id(&params.gts_id)).into_response()
+}
+
+async fn validate_id_body(Json(body): Json<GtsIdQuery>) -> Response {
+    Json(GtsOps::validate_id(&body.gts_id)).into_response()
+}
+
+async fn validate_instance(
+    State(state): State<AppState>,
+    Json(body): Json<ValidateInstanceRequest>,
+) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.validate_instance(&body.instance_id)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn validate_schema(
+    State(state): State<AppState>,
+    Json(body): Json<ValidateSchemaRequest>,
+) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.validate_schema(&body.schema_id)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn extract_id(State(state): State<AppState>, Json(body): Json<Value>) -> Response {
+    match lock_ops(&state) {
+        Ok(ops) => Json(ops.extract_id(&body)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn parse_id(Query(params): Query<GtsIdQuery>) -> Response {
+    Json(GtsOps::parse_id(&params.gts_id)).into_response()
+}
+
+async fn match_id_pattern(Query(params): Query<MatchIdQuery>) -> Response {
+    Json(GtsOps::match_id_pattern(&params.candidate, &params.pattern)).into_response()
+}
+
+async fn id_to_uuid(Query(params): Query<GtsIdQuery>) -> Response {
+    Json(GtsOps::uuid(&params.gts_id)).into_response()
+}
+
+async fn schema_graph(State(state): State<AppState>, Query(params): Query<GtsIdQuery>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.schema_graph(&params.gts_id)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn compatibility(
+    State(state): State<AppState>,
+    Query(params): Query<CompatibilityQuery>,
+) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => {
+            Json(ops.compatibility(&params.old_schema_id, &params.new_schema_id)).into_response()
+        }
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn cast(State(state): State<AppState>, Json(body): Json<CastRequest>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.cast(&body.instance_id, &body.to_schema_id)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn query(State(state): State<AppState>, Query(params): Query<QueryParams>) -> Response {
+    match lock_ops(&state) {
+        Ok(ops) => Json(ops.query(&params.expr, params.limit)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
+
+async fn attr(State(state): State<AppState>, Query(params): Query<AttrQuery>) -> Response {
+    match lock_ops(&state) {
+        Ok(mut ops) => Json(ops.attr(&params.gts_with_path)).into_response(),
+        Err(response) => response.into_response(),
+    }
+}
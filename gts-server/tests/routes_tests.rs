@@ -0,0 +1,166 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use gts::GtsOps;
+use gts_server::{AppState, create_router};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const SCHEMA_ID: &str = "gts.x.test.server.user.v1.0~";
+const SCHEMA_V1_1: &str = "gts.x.test.server.user.v1.1~";
+const INSTANCE_ID: &str = "gts.x.test.server.user.v1.0~x.test.server.alice.v1";
+
+fn user_schema(id: &str) -> Value {
+    json!({
+        "$id": format!("gts://{id}"),
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "required": ["id", "name"],
+        "properties": {"id": {"type": "string"}, "name": {"type": "string"}}
+    })
+}
+
+async fn send(app: &Router, method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn create_app() -> Router {
+    let app = create_router(AppState::new(GtsOps::new(None, None, 0)));
+    for content in [
+        user_schema(SCHEMA_ID),
+        json!({"id": INSTANCE_ID, "name": "Alice"}),
+    ] {
+        let (status, _) = send(&app, Method::POST, "/entities", Some(content)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/schemas",
+        Some(json!({"type_id": SCHEMA_V1_1, "schema": user_schema(SCHEMA_V1_1)})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    app
+}
+
+#[tokio::test]
+async fn test_validate_routes() {
+    let app = create_app().await;
+
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/validate/id",
+        Some(json!({"gts_id": SCHEMA_ID})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["valid"], true);
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        &format!("/validate-id?gts_id={SCHEMA_ID}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["valid"], true);
+
+    let (_, body) = send(
+        &app,
+        Method::POST,
+        "/validate-instance",
+        Some(json!({"instance_id": INSTANCE_ID})),
+    )
+    .await;
+    assert_eq!(body["ok"], true, "{body}");
+
+    let (_, body) = send(
+        &app,
+        Method::POST,
+        "/validate/schema",
+        Some(json!({"schema_id": SCHEMA_ID})),
+    )
+    .await;
+    assert_eq!(body["ok"], true, "{body}");
+}
+
+#[tokio::test]
+async fn test_entity_routes() {
+    let app = create_app().await;
+
+    let (status, body) = send(&app, Method::GET, &format!("/entities/{INSTANCE_ID}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"]["name"], "Alice");
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        "/entities/gts.x.test.server.user.v1.0~x.test.server.missing.v1",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ok"], false);
+
+    let (status, body) = send(&app, Method::POST, "/entities", Some(json!({"name": "x"}))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["ok"], false);
+}
+
+#[tokio::test]
+async fn test_query_cast_and_compatibility_routes() {
+    let app = create_app().await;
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        "/query?expr=gts.x.test.server.user.*&limit=10",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["count"], 3);
+
+    let (status, body) = send(&app, Method::GET, "/query?expr=not-a-gts-id", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(body["error"], "");
+
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/cast",
+        Some(json!({"instance_id": INSTANCE_ID, "to_schema_id": SCHEMA_V1_1})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("error").is_none_or(Value::is_null), "{body}");
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        &format!("/compatibility?old_schema_id={SCHEMA_ID}&new_schema_id={SCHEMA_V1_1}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["is_backward_compatible"], true);
+}
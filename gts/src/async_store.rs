@@ -0,0 +1,169 @@
+//! [`GtsStore`] shared between async tasks, enabled with the `async-store` feature.
+//!
+//! [`AsyncGtsStore`] is a cheaply cloneable handle around a store behind a
+//! `tokio::sync::RwLock`: lookups and queries take the read lock and run
+//! concurrently, registrations take the write lock. Entities can be loaded from an
+//! [`AsyncGtsReader`], the async counterpart of [`GtsReader`](crate::store::GtsReader).
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::entities::GtsEntity;
+use crate::store::{GtsStore, GtsStoreQueryResult, StoreError};
+
+/// Async source of entities, e.g. a remote registry or a database.
+pub trait AsyncGtsReader: Send {
+    /// Yields every entity the reader provides.
+    fn stream(&mut self) -> impl Future<Output = Vec<GtsEntity>> + Send;
+}
+
+#[derive(Clone)]
+pub struct AsyncGtsStore {
+    inner: Arc<RwLock<GtsStore>>,
+}
+
+impl Default for AsyncGtsStore {
+    fn default() -> Self {
+        Self::new(GtsStore::new(None))
+    }
+}
+
+impl AsyncGtsStore {
+    #[must_use]
+    pub fn new(store: GtsStore) -> Self {
+        AsyncGtsStore {
+            inner: Arc::new(RwLock::new(store)),
+        }
+    }
+
+    /// Registers every entity streamed by `reader` and returns how many were added.
+    ///
+    /// # Errors
+    /// Returns `StoreError::InvalidEntity` if a streamed entity has no effective ID;
+    /// entities streamed before it stay registered.
+    pub async fn load<R: AsyncGtsReader>(&self, reader: &mut R) -> Result<usize, StoreError> {
+        let entities = reader.stream().await;
+        let mut store = self.inner.write().await;
+        let count = entities.len();
+        for entity in entities {
+            store.register(entity)?;
+        }
+        Ok(count)
+    }
+
+    /// Registers an entity in the store.
+    ///
+    /// # Errors
+    /// Returns `StoreError::InvalidEntity` if the entity has no effective ID.
+    pub async fn register(&self, entity: GtsEntity) -> Result<(), StoreError> {
+        self.inner.write().await.register(entity)
+    }
+
+    /// Returns a copy of the entity with the given ID.
+    ///
+    /// Loaded entities are served under the read lock; only a miss takes the write
+    /// lock to consult the store's reader.
+    pub async fn get(&self, entity_id: &str) -> Option<GtsEntity> {
+        if let Some(entity) = self.inner.read().await.get_cached(entity_id) {
+            return Some(entity.clone());
+        }
+        self.inner.write().await.get(entity_id).cloned()
+    }
+
+    /// Runs [`GtsStore::query`] under the read lock.
+    pub async fn query(&self, expr: &str, limit: usize) -> GtsStoreQueryResult {
+        self.inner.read().await.query(expr, limit)
+    }
+
+    /// Locks the store for reading, for operations not wrapped by this type.
+    pub async fn read(&self) -> RwLockReadGuard<'_, GtsStore> {
+        self.inner.read().await
+    }
+
+    /// Locks the store for writing, for operations not wrapped by this type.
+    pub async fn write(&self) -> RwLockWriteGuard<'_, GtsStore> {
+        self.inner.write().await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::entities::GtsConfig;
+    use serde_json::json;
+
+    const SCHEMA_ID: &str = "gts.x.test.async.user.v1~";
+    const INSTANCE_ID: &str = "gts.x.test.async.user.v1~x.test.async.alice.v1";
+
+    fn instance(id: &str, name: &str) -> GtsEntity {
+        let cfg = GtsConfig::default();
+        GtsEntity::new(
+            None,
+            None,
+            &json!({"id": id, "name": name}),
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        )
+    }
+
+    struct VecReader(Vec<GtsEntity>);
+
+    impl AsyncGtsReader for VecReader {
+        async fn stream(&mut self) -> Vec<GtsEntity> {
+            std::mem::take(&mut self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_get_and_query() {
+        let store = AsyncGtsStore::default();
+        store
+            .write()
+            .await
+            .register_schema(SCHEMA_ID, &json!({"type": "object"}))
+            .unwrap();
+        store
+            .register(instance(INSTANCE_ID, "Alice"))
+            .await
+            .unwrap();
+
+        let entity = store.get(INSTANCE_ID).await.unwrap();
+        assert_eq!(entity.content["name"], "Alice");
+        assert!(
+            store
+                .get("gts.x.test.async.user.v1~x.test.async.bob.v1")
+                .await
+                .is_none()
+        );
+
+        let result = store.query("gts.x.test.async.user.*", 10).await;
+        assert_eq!(result.count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_load_from_async_reader_across_tasks() {
+        let store = AsyncGtsStore::default();
+        let mut reader = VecReader(vec![
+            instance(INSTANCE_ID, "Alice"),
+            instance("gts.x.test.async.user.v1~x.test.async.bob.v1", "Bob"),
+        ]);
+        assert_eq!(store.load(&mut reader).await.unwrap(), 2);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let store = store.clone();
+                tokio::spawn(async move { store.query("gts.x.test.async.*", 10).await.count })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), 2);
+        }
+    }
+}
@@ -1,10 +1,18 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
-use crate::gts::{GTS_URI_PREFIX, GtsID};
+use crate::gts::{GtsError, GtsID, GtsWildcard, strip_gts_uri};
 use crate::path_resolver::JsonPathResolver;
 use crate::schema_cast::{GtsEntityCastResult, SchemaCastError};
+use crate::store::GtsStore;
+
+/// `selected_schema_id_field` of an entity whose schema ID was set with
+/// [`GtsEntity::with_schema_id`] rather than read from its content.
+pub(crate) const MANUAL_SCHEMA_ID_FIELD: &str = "manual";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
@@ -24,7 +32,51 @@ pub struct ValidationResult {
     pub errors: Vec<ValidationError>,
 }
 
-#[derive(Debug, Clone)]
+impl ValidationError {
+    /// Split `instance_path` (an RFC 6901 JSON Pointer) into its decoded components.
+    ///
+    /// `~1` is decoded to `/` and `~0` to `~`; components without escapes are borrowed.
+    /// An empty path (the document root) yields no segments.
+    #[must_use]
+    pub fn path_segments(&self) -> Vec<Cow<'_, str>> {
+        let Some(path) = self.instance_path.strip_prefix('/') else {
+            return Vec::new();
+        };
+        path.split('/')
+            .map(|segment| {
+                if segment.contains('~') {
+                    Cow::Owned(segment.replace("~1", "/").replace("~0", "~"))
+                } else {
+                    Cow::Borrowed(segment)
+                }
+            })
+            .collect()
+    }
+}
+
+impl ValidationResult {
+    /// Returns `true` if there are no validation errors.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the first validation error, if any.
+    #[must_use]
+    pub fn first_error(&self) -> Option<&ValidationError> {
+        self.errors.first()
+    }
+
+    /// Combine the errors of two validation passes.
+    #[must_use]
+    pub fn merge(&self, other: &ValidationResult) -> ValidationResult {
+        ValidationResult {
+            errors: self.errors.iter().chain(&other.errors).cloned().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsFile {
     pub path: String,
     pub name: String,
@@ -56,12 +108,38 @@ impl GtsFile {
             validation: ValidationResult::default(),
         }
     }
+
+    /// Number of entities in the file (array items, or 1 for a single object).
+    #[must_use]
+    pub fn entity_count(&self) -> usize {
+        self.sequences_count
+    }
+
+    /// Content of the entity at sequence `index`.
+    #[must_use]
+    pub fn entity_at(&self, index: usize) -> Option<&Value> {
+        self.sequence_content.get(&index)
+    }
+
+    /// Iterates over the entity contents in document order (by sequence index).
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        let mut entries: Vec<(&usize, &Value)> = self.sequence_content.iter().collect();
+        entries.sort_unstable_by_key(|(index, _)| **index);
+        entries.into_iter().map(|(_, value)| value)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsConfig {
     pub entity_id_fields: Vec<String>,
     pub schema_id_fields: Vec<String>,
+    /// Reject instances without an ID field instead of falling back to the file path.
+    #[serde(default)]
+    pub strict: bool,
+    /// Schema ID for instances that have none of the `schema_id_fields`, for collections
+    /// where every document conforms to the same schema.
+    #[serde(default)]
+    pub default_schema_id: Option<String>,
 }
 
 impl Default for GtsConfig {
@@ -88,17 +166,208 @@ impl Default for GtsConfig {
                 "type".to_owned(),
                 "schema".to_owned(),
             ],
+            strict: false,
+            default_schema_id: None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+impl GtsConfig {
+    /// Load a configuration from a JSON file such as `gts.config.json`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a JSON object.
+    pub fn from_file(path: &Path) -> Result<GtsConfig, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        let value: Value = serde_json::from_str(&content)?;
+        Ok(Self::from_json(&value)?)
+    }
+
+    /// Build a configuration from a JSON object.
+    ///
+    /// Missing or non-array `entity_id_fields` / `schema_id_fields` fall back to the defaults,
+    /// `strict` defaults to `false` and `default_schema_id` to none.
+    ///
+    /// # Errors
+    /// Returns an error if `value` is not a JSON object.
+    pub fn from_json(value: &Value) -> Result<GtsConfig, serde_json::Error> {
+        let data: HashMap<String, Value> = serde_json::from_value(value.clone())?;
+        Ok(Self::from_data(&data))
+    }
+
+    /// Start building a custom configuration.
+    #[must_use]
+    pub fn builder() -> GtsConfigBuilder {
+        GtsConfigBuilder::default()
+    }
+
+    /// Add an entity ID field with the highest priority.
+    pub fn add_entity_id_field(&mut self, name: &str) {
+        self.entity_id_fields.insert(0, name.to_owned());
+    }
+
+    /// Add a schema ID field with the highest priority.
+    pub fn add_schema_id_field(&mut self, name: &str) {
+        self.schema_id_fields.insert(0, name.to_owned());
+    }
+
+    /// Combine a base configuration with an override.
+    ///
+    /// The override's ID fields are appended to the base lists, skipping duplicates,
+    /// strict mode is enabled if either configuration enables it, and the override's
+    /// `default_schema_id` is used when set.
+    #[must_use]
+    pub fn merge(base: &GtsConfig, overrides: &GtsConfig) -> GtsConfig {
+        fn merge_fields(base: &[String], overrides: &[String]) -> Vec<String> {
+            let mut merged = base.to_vec();
+            for field in overrides {
+                if !merged.contains(field) {
+                    merged.push(field.clone());
+                }
+            }
+            merged
+        }
+
+        GtsConfig {
+            entity_id_fields: merge_fields(&base.entity_id_fields, &overrides.entity_id_fields),
+            schema_id_fields: merge_fields(&base.schema_id_fields, &overrides.schema_id_fields),
+            strict: base.strict || overrides.strict,
+            default_schema_id: overrides
+                .default_schema_id
+                .clone()
+                .or_else(|| base.default_schema_id.clone()),
+        }
+    }
+
+    pub(crate) fn from_data(data: &HashMap<String, Value>) -> GtsConfig {
+        let default_cfg = GtsConfig::default();
+
+        let entity_id_fields = data
+            .get("entity_id_fields")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or(default_cfg.entity_id_fields);
+
+        let schema_id_fields = data
+            .get("schema_id_fields")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or(default_cfg.schema_id_fields);
+
+        let strict = data
+            .get("strict")
+            .and_then(Value::as_bool)
+            .unwrap_or(default_cfg.strict);
+
+        let default_schema_id = data
+            .get("default_schema_id")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+
+        GtsConfig {
+            entity_id_fields,
+            schema_id_fields,
+            strict,
+            default_schema_id,
+        }
+    }
+}
+
+/// Builder for [`GtsConfig`].
+///
+/// Fields are checked in the order they are added. A list left empty falls back
+/// to the [`GtsConfig::default`] fields.
+#[derive(Debug, Clone, Default)]
+pub struct GtsConfigBuilder {
+    entity_id_fields: Vec<String>,
+    schema_id_fields: Vec<String>,
+    strict: bool,
+    default_schema_id: Option<String>,
+}
+
+impl GtsConfigBuilder {
+    /// Add a field to look up the entity ID in.
+    #[must_use]
+    pub fn entity_id_field(mut self, name: &str) -> Self {
+        self.entity_id_fields.push(name.to_owned());
+        self
+    }
+
+    /// Add a field to look up the schema ID in.
+    #[must_use]
+    pub fn schema_id_field(mut self, name: &str) -> Self {
+        self.schema_id_fields.push(name.to_owned());
+        self
+    }
+
+    /// Enable or disable strict mode (see [`GtsConfig::strict`]).
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Set the schema ID for instances without a type field (see [`GtsConfig::default_schema_id`]).
+    #[must_use]
+    pub fn default_schema_id(mut self, schema_id: &str) -> Self {
+        self.default_schema_id = Some(schema_id.to_owned());
+        self
+    }
+
+    /// Build the configuration.
+    #[must_use]
+    pub fn build(self) -> GtsConfig {
+        let default_cfg = GtsConfig::default();
+        GtsConfig {
+            entity_id_fields: if self.entity_id_fields.is_empty() {
+                default_cfg.entity_id_fields
+            } else {
+                self.entity_id_fields
+            },
+            schema_id_fields: if self.schema_id_fields.is_empty() {
+                default_cfg.schema_id_fields
+            } else {
+                self.schema_id_fields
+            },
+            strict: self.strict,
+            default_schema_id: self.default_schema_id,
+        }
+    }
+}
+
+/// A single difference between two entity contents.
+///
+/// `old` is `None` for additions and `new` is `None` for removals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentDiff {
+    /// RFC 6901 JSON Pointer to the changed value (empty for the document root).
+    pub path: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsRef {
     pub id: String,
     pub source_path: String,
 }
 
-#[derive(Debug, Clone)]
+impl GtsRef {
+    /// Look up the referenced entity in the store.
+    pub fn resolve_in_store<'a>(&self, store: &'a mut GtsStore) -> Option<&'a GtsEntity> {
+        store.get(&self.id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsEntity {
     /// The GTS ID if the entity has one (either from `id` field for well-known instances,
     /// or from `$id` field for schemas). None for anonymous instances.
@@ -235,7 +504,7 @@ impl GtsEntity {
                     return;
                 }
 
-                let normalized = trimmed.strip_prefix(GTS_URI_PREFIX).unwrap_or(trimmed);
+                let normalized = strip_gts_uri(trimmed).unwrap_or(trimmed);
                 if GtsID::is_valid(normalized) {
                     self.gts_id = GtsID::new(normalized).ok();
                     self.instance_id = Some(normalized.to_owned());
@@ -356,6 +625,27 @@ impl GtsEntity {
             self.schema_id = self.get_type_field_value(cfg);
         }
 
+        // PRIORITY 3: Configured default for collections that omit the type field
+        if self.schema_id.is_none() {
+            self.schema_id.clone_from(&cfg.default_schema_id);
+        }
+
+        // In strict mode, an instance without an ID field is an error
+        if self.instance_id.is_none() && cfg.strict {
+            self.validation.errors.push(ValidationError {
+                instance_path: String::new(),
+                schema_path: String::new(),
+                keyword: "required".to_owned(),
+                message: format!(
+                    "Instance has no ID field (expected one of: {})",
+                    cfg.entity_id_fields.join(", ")
+                ),
+                params: HashMap::new(),
+                data: None,
+            });
+            return;
+        }
+
         // If still no instance_id, fall back to file path
         if self.instance_id.is_none()
             && let Some(ref file) = self.file
@@ -417,6 +707,23 @@ impl GtsEntity {
         JsonPathResolver::new(gts_id, self.content.clone()).resolve(path)
     }
 
+    /// Sets the schema ID after construction, e.g. once the store has resolved the type.
+    ///
+    /// The content is left unchanged and `selected_schema_id_field` becomes `"manual"`.
+    #[must_use]
+    pub fn with_schema_id(mut self, schema_id: &str) -> Self {
+        self.schema_id = Some(schema_id.to_owned());
+        self.selected_schema_id_field = Some(MANUAL_SCHEMA_ID_FIELD.to_owned());
+        self
+    }
+
+    /// Returns the content value at `path` (same syntax as [`GtsEntity::resolve_path`]),
+    /// or `None` if the path does not exist.
+    #[must_use]
+    pub fn content_at_path(&self, path: &str) -> Option<&Value> {
+        JsonPathResolver::lookup(&self.content, path)
+    }
+
     /// Casts this entity to a different schema.
     ///
     /// # Errors
@@ -557,10 +864,7 @@ impl GtsEntity {
                     format!("{path}.$ref")
                 };
                 // Normalize: strip gts:// prefix for canonical GTS ID storage
-                let normalized_ref = ref_str
-                    .strip_prefix(GTS_URI_PREFIX)
-                    .unwrap_or(ref_str)
-                    .to_owned();
+                let normalized_ref = strip_gts_uri(ref_str).unwrap_or(ref_str).to_owned();
                 return Some(GtsRef {
                     id: normalized_ref,
                     source_path: ref_path,
@@ -590,7 +894,7 @@ impl GtsEntity {
                 // Strip the "gts://" URI prefix ONLY for $id field (JSON Schema compatibility)
                 // The gts:// prefix is ONLY valid in the $id field of JSON Schema
                 let normalized = if field == "$id" {
-                    trimmed.strip_prefix(GTS_URI_PREFIX).unwrap_or(trimmed)
+                    strip_gts_uri(trimmed).unwrap_or(trimmed)
                 } else {
                     trimmed
                 };
@@ -622,6 +926,301 @@ impl GtsEntity {
         None
     }
 
+    /// Check whether this instance conforms to a schema ID or wildcard pattern.
+    ///
+    /// A pattern ending with `~` is compared exactly against the instance's schema ID;
+    /// a pattern containing `*` is matched as a [`GtsWildcard`]. Schemas never match.
+    #[must_use]
+    pub fn is_instance_of(&self, pattern: &str) -> bool {
+        if self.is_schema {
+            return false;
+        }
+        let Some(schema_id) = self.schema_id.as_deref() else {
+            return false;
+        };
+
+        if pattern.contains('*') {
+            let (Ok(wildcard), Ok(gts_id)) = (GtsWildcard::new(pattern), GtsID::new(schema_id))
+            else {
+                return false;
+            };
+            return gts_id.wildcard_match(&wildcard);
+        }
+
+        pattern.ends_with('~') && schema_id == pattern
+    }
+
+    /// Returns the schema IDs in this entity's chain, from the outermost to the innermost type.
+    ///
+    /// For `gts.x.core.events.type.v1~x.core.audit.event.v1~x.app.auth.login.v1` this is
+    /// `["gts.x.core.events.type.v1~", "gts.x.core.events.type.v1~x.core.audit.event.v1~"]`.
+    /// Anonymous instances use their schema ID; a schema's own ID is the last element.
+    #[must_use]
+    pub fn ancestry(&self) -> Vec<String> {
+        let id = match (&self.gts_id, &self.schema_id) {
+            (Some(gts_id), _) => gts_id.id.clone(),
+            (None, Some(schema_id)) if GtsID::is_valid(schema_id) => schema_id.clone(),
+            _ => return Vec::new(),
+        };
+
+        id.match_indices('~')
+            .map(|(pos, _)| id[..=pos].to_owned())
+            .collect()
+    }
+
+    /// Returns the `(major, minor)` version of the last segment of `schema_id`.
+    ///
+    /// For `gts.x.core.events.type.v1.2~` this is `Some((1, Some(2)))`; `None` if the
+    /// schema ID is missing or not a GTS ID.
+    #[must_use]
+    pub fn schema_version(&self) -> Option<(u32, Option<u32>)> {
+        GtsID::new(self.schema_id.as_deref()?).ok()?.version()
+    }
+
+    /// Merge a JSON patch into the entity content.
+    ///
+    /// Keys in `patch` overwrite existing keys; nested objects present on both sides are
+    /// merged recursively, and any other patch value replaces the existing one.
+    /// References are re-extracted afterwards.
+    pub fn merge_content(&mut self, patch: &Value) {
+        fn merge(target: &mut Value, patch: &Value) {
+            match (target, patch) {
+                (Value::Object(target), Value::Object(patch)) => {
+                    for (key, value) in patch {
+                        match target.get_mut(key) {
+                            Some(existing) => merge(existing, value),
+                            None => {
+                                target.insert(key.clone(), value.clone());
+                            }
+                        }
+                    }
+                }
+                (target, patch) => *target = patch.clone(),
+            }
+        }
+
+        merge(&mut self.content, patch);
+
+        self.gts_refs = self.extract_gts_ids_with_paths();
+        if self.is_schema {
+            self.schema_refs = self.extract_ref_strings_with_paths();
+        }
+    }
+
+    /// Fill in missing properties from the `default` keywords of the given schema.
+    ///
+    /// Existing values are never overwritten. Nested object properties are filled
+    /// recursively, and references are re-extracted afterwards.
+    pub fn apply_defaults(&mut self, schema: &Value) {
+        fn fill(target: &mut Map<String, Value>, schema: &Value) {
+            let schema = GtsEntityCastResult::effective_object_schema(schema);
+            let Some(props) = schema.get("properties").and_then(Value::as_object) else {
+                return;
+            };
+            for (prop, p_schema) in props {
+                if !target.contains_key(prop)
+                    && let Some(default) = p_schema.get("default")
+                {
+                    target.insert(prop.clone(), default.clone());
+                }
+                if p_schema.get("type").and_then(Value::as_str) == Some("object")
+                    && let Some(Value::Object(nested)) = target.get_mut(prop)
+                {
+                    fill(nested, p_schema);
+                }
+            }
+        }
+
+        if let Some(content) = self.content.as_object_mut() {
+            fill(content, schema);
+        }
+
+        self.gts_refs = self.extract_gts_ids_with_paths();
+        if self.is_schema {
+            self.schema_refs = self.extract_ref_strings_with_paths();
+        }
+    }
+
+    /// Remove fields that the schema does not allow and return their paths.
+    ///
+    /// Keys missing from `properties` are removed wherever the schema sets
+    /// `additionalProperties: false`; nested object properties are stripped recursively.
+    /// Paths use dot notation (e.g., `settings.debug`).
+    pub fn strip_to_schema(&mut self, schema: &Value) -> Vec<String> {
+        fn strip(
+            target: &mut Map<String, Value>,
+            schema: &Value,
+            base_path: &str,
+            removed: &mut Vec<String>,
+        ) {
+            let schema = GtsEntityCastResult::effective_object_schema(schema);
+            let props = schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default();
+            let join = |prop: &str| {
+                if base_path.is_empty() {
+                    prop.to_owned()
+                } else {
+                    format!("{base_path}.{prop}")
+                }
+            };
+
+            if schema.get("additionalProperties").and_then(Value::as_bool) == Some(false) {
+                target.retain(|key, _| {
+                    let keep = props.contains_key(key);
+                    if !keep {
+                        removed.push(join(key));
+                    }
+                    keep
+                });
+            }
+
+            for (prop, p_schema) in &props {
+                if let Some(Value::Object(nested)) = target.get_mut(prop) {
+                    strip(nested, p_schema, &join(prop), removed);
+                }
+            }
+        }
+
+        let mut removed = Vec::new();
+        if let Some(content) = self.content.as_object_mut() {
+            strip(content, schema, "", &mut removed);
+        }
+
+        self.gts_refs = self.extract_gts_ids_with_paths();
+        if self.is_schema {
+            self.schema_refs = self.extract_ref_strings_with_paths();
+        }
+        removed
+    }
+
+    /// Compute a structural diff from this entity's content to `other`'s.
+    ///
+    /// Objects and arrays are compared member by member; any other change is
+    /// reported at the deepest path where the values differ.
+    #[must_use]
+    pub fn diff_content(&self, other: &GtsEntity) -> Vec<ContentDiff> {
+        fn escape(key: &str) -> String {
+            key.replace('~', "~0").replace('/', "~1")
+        }
+
+        fn diff(old: &Value, new: &Value, path: &str, diffs: &mut Vec<ContentDiff>) {
+            match (old, new) {
+                (Value::Object(old_obj), Value::Object(new_obj)) => {
+                    for (key, old_val) in old_obj {
+                        let child = format!("{path}/{}", escape(key));
+                        match new_obj.get(key) {
+                            Some(new_val) => diff(old_val, new_val, &child, diffs),
+                            None => diffs.push(ContentDiff {
+                                path: child,
+                                old: Some(old_val.clone()),
+                                new: None,
+                            }),
+                        }
+                    }
+                    for (key, new_val) in new_obj {
+                        if !old_obj.contains_key(key) {
+                            diffs.push(ContentDiff {
+                                path: format!("{path}/{}", escape(key)),
+                                old: None,
+                                new: Some(new_val.clone()),
+                            });
+                        }
+                    }
+                }
+                (Value::Array(old_arr), Value::Array(new_arr)) => {
+                    for idx in 0..old_arr.len().max(new_arr.len()) {
+                        let child = format!("{path}/{idx}");
+                        match (old_arr.get(idx), new_arr.get(idx)) {
+                            (Some(old_val), Some(new_val)) => diff(old_val, new_val, &child, diffs),
+                            (old_val, new_val) => diffs.push(ContentDiff {
+                                path: child,
+                                old: old_val.cloned(),
+                                new: new_val.cloned(),
+                            }),
+                        }
+                    }
+                }
+                _ if old != new => diffs.push(ContentDiff {
+                    path: path.to_owned(),
+                    old: Some(old.clone()),
+                    new: Some(new.clone()),
+                }),
+                _ => {}
+            }
+        }
+
+        let mut diffs = Vec::new();
+        diff(&self.content, &other.content, "", &mut diffs);
+        diffs
+    }
+
+    /// Check that every `$ref` in this schema resolves in the store.
+    ///
+    /// Local references (starting with `#`) are not looked up. Returns the unresolved
+    /// reference IDs, in the order they appear.
+    pub fn validate_all_refs(&self, store: &mut GtsStore) -> Vec<String> {
+        let mut unresolved: Vec<String> = Vec::new();
+        for schema_ref in &self.schema_refs {
+            if schema_ref.id.starts_with('#') || unresolved.contains(&schema_ref.id) {
+                continue;
+            }
+            if schema_ref.resolve_in_store(store).is_none() {
+                unresolved.push(schema_ref.id.clone());
+            }
+        }
+        unresolved
+    }
+
+    /// Copies this entity under a different GTS ID, e.g. when migrating it to a new version.
+    ///
+    /// The ID field the entity was read from (`$id` for schemas, otherwise the selected
+    /// entity ID field, `id` by default) is set to `new_id` and the IDs are extracted
+    /// again with `cfg`. Other fields, including an explicit schema ID field, are copied
+    /// unchanged. File and list position are not carried over.
+    ///
+    /// # Errors
+    /// Returns `GtsError` if `new_id` is not a valid GTS ID, or if this entity is a schema
+    /// and `new_id` is not a schema ID (ending with `~`).
+    pub fn clone_with_new_id(&self, new_id: &str, cfg: &GtsConfig) -> Result<GtsEntity, GtsError> {
+        let gts_id = GtsID::new(new_id)?;
+        if self.is_schema && !gts_id.is_type() {
+            return Err(GtsError::Id {
+                id: new_id.to_owned(),
+                cause: "A schema must be copied under a schema ID ending with '~'".to_owned(),
+            });
+        }
+
+        let (field, value) = if self.is_schema {
+            ("$id".to_owned(), gts_id.to_uri())
+        } else {
+            (
+                self.selected_entity_field
+                    .clone()
+                    .unwrap_or_else(|| "id".to_owned()),
+                gts_id.id,
+            )
+        };
+        let mut content = self.content.clone();
+        if let Some(obj) = content.as_object_mut() {
+            obj.insert(field, Value::String(value));
+        }
+
+        Ok(GtsEntity::new(
+            None,
+            None,
+            &content,
+            Some(cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        ))
+    }
+
     /// Returns the effective ID for this entity (for store indexing and CLI output).
     /// - For schemas: the GTS ID from `$id` field
     /// - For well-known instances: the GTS ID from `id` field
@@ -643,6 +1242,20 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    fn entity_from(content: &Value, cfg: &GtsConfig) -> GtsEntity {
+        GtsEntity::new(
+            None,
+            None,
+            content,
+            Some(cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        )
+    }
+
     #[test]
     fn test_json_file_with_description() {
         let content = json!({
@@ -697,6 +1310,38 @@ mod tests {
         assert_eq!(entity.label, "file.json#0");
     }
 
+    #[test]
+    fn test_gts_file_entity_count_and_entity_at() {
+        let file = GtsFile::new(
+            "/path/to/file.json".to_owned(),
+            "file.json".to_owned(),
+            json!([{"id": "first"}, {"id": "second"}]),
+        );
+        assert_eq!(file.entity_count(), 2);
+        assert_eq!(file.entity_at(1), Some(&json!({"id": "second"})));
+        assert_eq!(file.entity_at(2), None);
+
+        let single = GtsFile::new(
+            "/path/to/single.json".to_owned(),
+            "single.json".to_owned(),
+            json!({"id": "only"}),
+        );
+        assert_eq!(single.entity_count(), 1);
+        assert_eq!(single.entity_at(0), Some(&json!({"id": "only"})));
+    }
+
+    #[test]
+    fn test_gts_file_iter_in_document_order() {
+        let items: Vec<Value> = (0..20).map(|i| json!({"line": i})).collect();
+        let file = GtsFile::new(
+            "/path/to/file.ndjson".to_owned(),
+            "file.ndjson".to_owned(),
+            Value::Array(items.clone()),
+        );
+        let iterated: Vec<&Value> = file.iter().collect();
+        assert_eq!(iterated, items.iter().collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_json_entity_with_file_no_sequence() {
         let file_content = json!({"id": "gts.vendor.package.namespace.type.v1.0"});
@@ -1573,4 +2218,723 @@ mod tests {
         assert!(entity.instance_id.is_none());
         assert!(entity.gts_id.is_none());
     }
+
+    #[test]
+    fn test_config_from_json() {
+        let cfg = GtsConfig::from_json(&json!({
+            "entity_id_fields": ["uuid", "id"]
+        }))
+        .unwrap();
+        assert_eq!(cfg.entity_id_fields, vec!["uuid", "id"]);
+        // Missing fields fall back to defaults
+        assert_eq!(cfg.schema_id_fields, GtsConfig::default().schema_id_fields);
+
+        assert!(GtsConfig::from_json(&json!(["not", "an", "object"])).is_err());
+    }
+
+    #[test]
+    fn test_config_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gts.config.json");
+        fs::write(
+            &path,
+            r#"{"entity_id_fields": ["customId"], "schema_id_fields": ["schemaId"]}"#,
+        )
+        .unwrap();
+
+        let cfg = GtsConfig::from_file(&path).unwrap();
+        assert_eq!(cfg.entity_id_fields, vec!["customId"]);
+        assert_eq!(cfg.schema_id_fields, vec!["schemaId"]);
+
+        assert!(GtsConfig::from_file(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let cfg = GtsConfig::builder()
+            .entity_id_field("uuid")
+            .entity_id_field("id")
+            .schema_id_field("kind")
+            .build();
+        assert_eq!(cfg.entity_id_fields, vec!["uuid", "id"]);
+        assert_eq!(cfg.schema_id_fields, vec!["kind"]);
+
+        // Lists that were not set keep the defaults
+        let cfg = GtsConfig::builder().entity_id_field("uuid").build();
+        assert_eq!(cfg.schema_id_fields, GtsConfig::default().schema_id_fields);
+    }
+
+    #[test]
+    fn test_config_add_fields_take_priority() {
+        let mut cfg = GtsConfig::default();
+        cfg.add_entity_id_field("uuid");
+        cfg.add_schema_id_field("kind");
+        assert_eq!(cfg.entity_id_fields[0], "uuid");
+        assert_eq!(cfg.schema_id_fields[0], "kind");
+
+        let content = json!({"uuid": "gts.x.core.events.topic.v1~x.app.topic.v1", "id": "other"});
+        let entity = entity_from(&content, &cfg);
+        assert_eq!(entity.selected_entity_field.as_deref(), Some("uuid"));
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_instance_without_id() {
+        let content = json!({"type": "gts.x.core.events.topic.v1~", "name": "test"});
+        let file = GtsFile::new(
+            "/tmp/instance.json".to_owned(),
+            "instance.json".to_owned(),
+            content.clone(),
+        );
+
+        // Non-strict: file path is used as the instance ID
+        let cfg = GtsConfig::default();
+        let entity = GtsEntity::new(
+            Some(file.clone()),
+            None,
+            &content,
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        assert_eq!(entity.instance_id.as_deref(), Some("/tmp/instance.json"));
+        assert!(entity.validation.errors.is_empty());
+
+        // Strict: no fallback, a validation error is recorded instead
+        let cfg = GtsConfig::builder().strict(true).build();
+        let entity = GtsEntity::new(
+            Some(file),
+            None,
+            &content,
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        assert_eq!(entity.effective_id(), None);
+        assert_eq!(entity.validation.errors.len(), 1);
+        assert_eq!(entity.validation.errors[0].keyword, "required");
+    }
+
+    #[test]
+    fn test_strict_mode_from_json() {
+        let cfg = GtsConfig::from_json(&json!({"strict": true})).unwrap();
+        assert!(cfg.strict);
+        assert!(!GtsConfig::default().strict);
+    }
+
+    #[test]
+    fn test_default_schema_id() {
+        let cfg = GtsConfig::from_json(&json!({"default_schema_id": "gts.x.test.dflt.item.v1~"}))
+            .unwrap();
+        assert_eq!(
+            cfg.default_schema_id.as_deref(),
+            Some("gts.x.test.dflt.item.v1~")
+        );
+        assert!(GtsConfig::default().default_schema_id.is_none());
+
+        // Applied when the instance has no type field
+        let entity = entity_from(&json!({"id": "7c9e6679-7425-40de-944b-e07fc1f90ae7"}), &cfg);
+        assert_eq!(
+            entity.schema_id.as_deref(),
+            Some("gts.x.test.dflt.item.v1~")
+        );
+        assert!(entity.selected_schema_id_field.is_none());
+
+        // An explicit type field or a chained ID wins
+        let entity = entity_from(
+            &json!({
+                "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+                "type": "gts.x.test.dflt.other.v1~"
+            }),
+            &cfg,
+        );
+        assert_eq!(
+            entity.schema_id.as_deref(),
+            Some("gts.x.test.dflt.other.v1~")
+        );
+        let entity = entity_from(
+            &json!({"id": "gts.x.test.dflt.chain.v1~x.test.dflt.a.v1"}),
+            &cfg,
+        );
+        assert_eq!(
+            entity.schema_id.as_deref(),
+            Some("gts.x.test.dflt.chain.v1~")
+        );
+
+        let built = GtsConfig::builder()
+            .default_schema_id("gts.x.test.dflt.item.v1~")
+            .build();
+        assert_eq!(built.default_schema_id, cfg.default_schema_id);
+    }
+
+    #[test]
+    fn test_config_merge() {
+        let base = GtsConfig::builder()
+            .entity_id_field("gtsId")
+            .entity_id_field("id")
+            .schema_id_field("type")
+            .default_schema_id("gts.x.test.merge.item.v1~")
+            .build();
+        let overrides = GtsConfig::builder()
+            .entity_id_field("uuid")
+            .entity_id_field("id")
+            .schema_id_field("kind")
+            .strict(true)
+            .build();
+
+        let merged = GtsConfig::merge(&base, &overrides);
+        assert_eq!(merged.entity_id_fields, vec!["gtsId", "id", "uuid"]);
+        assert_eq!(merged.schema_id_fields, vec!["type", "kind"]);
+        assert!(merged.strict);
+        assert_eq!(
+            merged.default_schema_id.as_deref(),
+            Some("gts.x.test.merge.item.v1~")
+        );
+    }
+
+    #[test]
+    fn test_validation_result_helpers() {
+        let error = |keyword: &str| ValidationError {
+            instance_path: String::new(),
+            schema_path: String::new(),
+            keyword: keyword.to_owned(),
+            message: format!("{keyword} failed"),
+            params: HashMap::new(),
+            data: None,
+        };
+
+        let empty = ValidationResult::default();
+        assert!(empty.is_valid());
+        assert!(empty.first_error().is_none());
+
+        let first = ValidationResult {
+            errors: vec![error("type")],
+        };
+        let second = ValidationResult {
+            errors: vec![error("required"), error("enum")],
+        };
+        assert!(!first.is_valid());
+        assert_eq!(first.first_error().unwrap().keyword, "type");
+
+        let merged = first.merge(&second);
+        let keywords: Vec<&str> = merged.errors.iter().map(|e| e.keyword.as_str()).collect();
+        assert_eq!(keywords, vec!["type", "required", "enum"]);
+        assert!(empty.merge(&empty).is_valid());
+    }
+
+    #[test]
+    fn test_validation_error_path_segments() {
+        let error = |path: &str| ValidationError {
+            instance_path: path.to_owned(),
+            schema_path: String::new(),
+            keyword: "type".to_owned(),
+            message: String::new(),
+            params: HashMap::new(),
+            data: None,
+        };
+
+        assert_eq!(
+            error("/user/profile/name").path_segments(),
+            vec!["user", "profile", "name"]
+        );
+        assert_eq!(error("/items/0").path_segments(), vec!["items", "0"]);
+        // RFC 6901 escapes: ~1 is '/', ~0 is '~' (and "~01" decodes to "~1", not "/")
+        assert_eq!(
+            error("/a~1b/c~0d/~01").path_segments(),
+            vec!["a/b", "c~d", "~1"]
+        );
+        assert_eq!(error("/").path_segments(), vec![""]);
+        assert!(error("").path_segments().is_empty());
+    }
+
+    #[test]
+    fn test_is_instance_of() {
+        let content = json!({
+            "id": "gts.x.core.events.type.v1~x.core.audit.event.v1~x.app.auth.login.v1",
+            "name": "login"
+        });
+        let cfg = GtsConfig::default();
+        let entity = entity_from(&content, &cfg);
+
+        assert!(entity.is_instance_of("gts.x.core.events.type.v1~x.core.audit.event.v1~"));
+        assert!(!entity.is_instance_of("gts.x.core.events.type.v1~"));
+        assert!(entity.is_instance_of("gts.x.core.events.type.v1~x.core.*"));
+        assert!(entity.is_instance_of("gts.x.core.events.*"));
+        assert!(!entity.is_instance_of("gts.y.core.*"));
+        // Neither an exact schema ID nor a wildcard
+        assert!(!entity.is_instance_of("gts.x.core.events.type.v1~x.core.audit.event.v1"));
+    }
+
+    #[test]
+    fn test_is_instance_of_schema_is_false() {
+        let content = json!({
+            "$id": "gts://gts.x.core.events.type.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        });
+        let cfg = GtsConfig::default();
+        let entity = entity_from(&content, &cfg);
+        assert!(!entity.is_instance_of("gts.x.core.events.type.v1~"));
+        assert!(!entity.is_instance_of("gts.x.*"));
+    }
+
+    #[test]
+    fn test_merge_content() {
+        let content = json!({
+            "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+            "name": "login",
+            "settings": {"retries": 3, "timeout": 10}
+        });
+        let cfg = GtsConfig::default();
+        let mut entity = entity_from(&content, &cfg);
+        let refs_before = entity.gts_refs.len();
+
+        entity.merge_content(&json!({
+            "name": "logout",
+            "settings": {"timeout": 30},
+            "topic": "gts.x.core.events.topic.v1~x.app.auth.topic.v1"
+        }));
+
+        assert_eq!(
+            entity.content,
+            json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "name": "logout",
+                "settings": {"retries": 3, "timeout": 30},
+                "topic": "gts.x.core.events.topic.v1~x.app.auth.topic.v1"
+            })
+        );
+        // The new GTS ID in "topic" is picked up as a reference
+        assert_eq!(entity.gts_refs.len(), refs_before + 1);
+        assert!(entity.gts_refs.iter().any(|r| r.source_path == "topic"));
+    }
+
+    #[test]
+    fn test_with_schema_id() {
+        let content = json!({"id": "7c9e6679-7425-40de-944b-e07fc1f90ae7", "name": "x"});
+        let entity = entity_from(&content, &GtsConfig::default());
+        assert!(entity.schema_id.is_none());
+
+        let entity = entity.with_schema_id("gts.x.test.manual.item.v1~");
+        assert_eq!(
+            entity.schema_id.as_deref(),
+            Some("gts.x.test.manual.item.v1~")
+        );
+        assert_eq!(entity.selected_schema_id_field.as_deref(), Some("manual"));
+        assert_eq!(entity.content, content);
+    }
+
+    #[test]
+    fn test_content_at_path() {
+        let entity = entity_from(
+            &json!({
+                "id": "gts.x.test.path.item.v1~x.test.path.a.v1",
+                "user": {"name": "Ann", "tags": ["a", {"k": 1}]}
+            }),
+            &GtsConfig::default(),
+        );
+
+        assert_eq!(entity.content_at_path("user.name"), Some(&json!("Ann")));
+        assert_eq!(entity.content_at_path("user/tags[1].k"), Some(&json!(1)));
+        assert_eq!(entity.content_at_path("user.tags.0"), Some(&json!("a")));
+        assert_eq!(entity.content_at_path(""), Some(&entity.content));
+        assert_eq!(entity.content_at_path("user.missing"), None);
+        assert_eq!(entity.content_at_path("user.tags[5]"), None);
+        assert_eq!(entity.content_at_path("user.name.first"), None);
+        for path in ["user.name", "user/tags[1].k", "user.missing"] {
+            assert_eq!(
+                entity.content_at_path(path).cloned(),
+                entity.resolve_path(path).value
+            );
+        }
+    }
+
+    #[test]
+    fn test_clone_with_new_id() {
+        let cfg = GtsConfig::default();
+
+        let instance = entity_from(
+            &json!({
+                "id": "gts.x.test.clone.item.v1~x.test.clone.a.v1",
+                "name": "a"
+            }),
+            &cfg,
+        );
+        let copy = instance
+            .clone_with_new_id("gts.x.test.clone.item.v2~x.test.clone.a.v1", &cfg)
+            .unwrap();
+        assert_eq!(
+            copy.effective_id().as_deref(),
+            Some("gts.x.test.clone.item.v2~x.test.clone.a.v1")
+        );
+        assert_eq!(copy.schema_id.as_deref(), Some("gts.x.test.clone.item.v2~"));
+        assert_eq!(copy.content["name"], "a");
+        // The original is untouched
+        assert_eq!(
+            instance.content["id"],
+            "gts.x.test.clone.item.v1~x.test.clone.a.v1"
+        );
+
+        let schema = entity_from(
+            &json!({
+                "$id": "gts://gts.x.test.clone.item.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object"
+            }),
+            &cfg,
+        );
+        let copy = schema
+            .clone_with_new_id("gts.x.test.clone.item.v2~", &cfg)
+            .unwrap();
+        assert!(copy.is_schema);
+        assert_eq!(
+            copy.effective_id().as_deref(),
+            Some("gts.x.test.clone.item.v2~")
+        );
+        assert_eq!(copy.content["$id"], "gts://gts.x.test.clone.item.v2~");
+
+        assert!(
+            schema
+                .clone_with_new_id("gts.x.test.clone.item.v2~x.test.clone.a.v1", &cfg)
+                .is_err()
+        );
+        assert!(instance.clone_with_new_id("not-a-gts-id", &cfg).is_err());
+    }
+
+    #[test]
+    fn test_ancestry() {
+        let cfg = GtsConfig::default();
+
+        let well_known = entity_from(
+            &json!({
+                "id": "gts.x.core.events.type.v1~x.core.audit.event.v1~x.app.auth.login.v1"
+            }),
+            &cfg,
+        );
+        assert_eq!(
+            well_known.ancestry(),
+            vec![
+                "gts.x.core.events.type.v1~",
+                "gts.x.core.events.type.v1~x.core.audit.event.v1~"
+            ]
+        );
+
+        let anonymous = entity_from(
+            &json!({
+                "id": "7a1d2f34-5678-49ab-9012-abcdef123456",
+                "type": "gts.x.core.events.type.v1~x.core.audit.event.v1~"
+            }),
+            &cfg,
+        );
+        assert_eq!(well_known.ancestry(), anonymous.ancestry());
+
+        let schema = entity_from(
+            &json!({
+                "$id": "gts://gts.x.core.events.type.v1~x.core.audit.event.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#"
+            }),
+            &cfg,
+        );
+        assert_eq!(schema.ancestry(), anonymous.ancestry());
+
+        assert!(
+            entity_from(&json!({"name": "no id"}), &cfg)
+                .ancestry()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_schema_version() {
+        let cfg = GtsConfig::default();
+
+        let major_only = entity_from(
+            &json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1"
+            }),
+            &cfg,
+        );
+        assert_eq!(major_only.schema_version(), Some((1, None)));
+
+        let with_minor = entity_from(
+            &json!({
+                "id": "gts.x.core.events.type.v1~x.core.audit.event.v2.3~x.app.auth.login.v1"
+            }),
+            &cfg,
+        );
+        assert_eq!(with_minor.schema_version(), Some((2, Some(3))));
+
+        assert_eq!(
+            entity_from(&json!({"name": "no id"}), &cfg).schema_version(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_apply_defaults() {
+        let content = json!({
+            "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+            "region": "eu-west",
+            "settings": {"retries": 5}
+        });
+        let cfg = GtsConfig::default();
+        let mut entity = entity_from(&content, &cfg);
+
+        let schema = json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {
+                "id": {"type": "string"},
+                "region": {"type": "string", "default": "us-east"},
+                "enabled": {"type": "boolean", "default": true},
+                "settings": {
+                    "type": "object",
+                    "properties": {
+                        "retries": {"type": "integer", "default": 3},
+                        "timeout": {"type": "integer", "default": 10}
+                    }
+                },
+                "label": {"type": "string"}
+            }
+        });
+        entity.apply_defaults(&schema);
+
+        assert_eq!(
+            entity.content,
+            json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "region": "eu-west",
+                "enabled": true,
+                "settings": {"retries": 5, "timeout": 10}
+            })
+        );
+    }
+
+    #[test]
+    fn test_strip_to_schema() {
+        let content = json!({
+            "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+            "name": "login",
+            "extra": "dropped",
+            "settings": {"retries": 3, "debug": true},
+            "meta": {"anything": "kept"}
+        });
+        let cfg = GtsConfig::default();
+        let mut entity = entity_from(&content, &cfg);
+
+        let schema = json!({
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "id": {"type": "string"},
+                "name": {"type": "string"},
+                "settings": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {"retries": {"type": "integer"}}
+                },
+                "meta": {"type": "object"}
+            }
+        });
+        let removed = entity.strip_to_schema(&schema);
+
+        assert_eq!(removed, vec!["extra", "settings.debug"]);
+        assert_eq!(
+            entity.content,
+            json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "name": "login",
+                "settings": {"retries": 3},
+                "meta": {"anything": "kept"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_content() {
+        let cfg = GtsConfig::default();
+
+        let before = entity_from(
+            &json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "name": "login",
+                "obsolete": 1,
+                "settings": {"retries": 3, "a/b": "x"},
+                "tags": ["a", "b"]
+            }),
+            &cfg,
+        );
+        let after = entity_from(
+            &json!({
+                "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "name": "logout",
+                "settings": {"retries": 3, "a/b": "y"},
+                "tags": ["a"],
+                "added": true
+            }),
+            &cfg,
+        );
+
+        let diffs = before.diff_content(&after);
+        let expected = vec![
+            ContentDiff {
+                path: "/name".to_owned(),
+                old: Some(json!("login")),
+                new: Some(json!("logout")),
+            },
+            ContentDiff {
+                path: "/obsolete".to_owned(),
+                old: Some(json!(1)),
+                new: None,
+            },
+            ContentDiff {
+                path: "/settings/a~1b".to_owned(),
+                old: Some(json!("x")),
+                new: Some(json!("y")),
+            },
+            ContentDiff {
+                path: "/tags/1".to_owned(),
+                old: Some(json!("b")),
+                new: None,
+            },
+            ContentDiff {
+                path: "/added".to_owned(),
+                old: None,
+                new: Some(json!(true)),
+            },
+        ];
+        assert_eq!(diffs, expected);
+        assert!(before.diff_content(&before).is_empty());
+    }
+
+    #[test]
+    fn test_entity_serde_roundtrip() {
+        let content = json!({
+            "id": "gts.x.core.events.type.v1~x.app.auth.login.v1",
+            "topic": "gts.x.core.events.topic.v1~x.app.auth.topic.v1"
+        });
+        let file = GtsFile::new(
+            "/tmp/login.json".to_owned(),
+            "login.json".to_owned(),
+            content.clone(),
+        );
+        let cfg = GtsConfig::default();
+        let entity = GtsEntity::new(
+            Some(file),
+            None,
+            &content,
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+
+        let json = serde_json::to_value(&entity).unwrap();
+        assert_eq!(
+            json["gts_id"],
+            "gts.x.core.events.type.v1~x.app.auth.login.v1"
+        );
+
+        let restored: GtsEntity = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.gts_id, entity.gts_id);
+        assert_eq!(restored.schema_id, entity.schema_id);
+        assert_eq!(restored.content, entity.content);
+        assert_eq!(restored.gts_refs.len(), entity.gts_refs.len());
+        assert_eq!(restored.label, entity.label);
+        let restored_file = restored.file.unwrap();
+        assert_eq!(restored_file.path, "/tmp/login.json");
+        assert_eq!(restored_file.sequence_content.get(&0), Some(&content));
+    }
+
+    #[test]
+    fn test_entity_deserialize_rejects_invalid_gts_id() {
+        let mut json = serde_json::to_value(GtsEntity::new(
+            None,
+            None,
+            &json!({"name": "x"}),
+            None,
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        ))
+        .unwrap();
+        json["gts_id"] = json!("not-a-gts-id");
+        assert!(serde_json::from_value::<GtsEntity>(json).is_err());
+    }
+
+    #[test]
+    fn test_gts_ref_resolve_in_store() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.core.events.topic.v1~",
+                &json!({
+                    "$id": "gts://gts.x.core.events.topic.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object"
+                }),
+            )
+            .unwrap();
+
+        let found = GtsRef {
+            id: "gts.x.core.events.topic.v1~".to_owned(),
+            source_path: "topic".to_owned(),
+        };
+        let entity = found.resolve_in_store(&mut store).unwrap();
+        assert_eq!(
+            entity.effective_id().as_deref(),
+            Some("gts.x.core.events.topic.v1~")
+        );
+
+        let missing = GtsRef {
+            id: "gts.x.core.events.missing.v1~".to_owned(),
+            source_path: "topic".to_owned(),
+        };
+        assert!(missing.resolve_in_store(&mut store).is_none());
+    }
+
+    #[test]
+    fn test_validate_all_refs() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.core.events.type.v1~",
+                &json!({
+                    "$id": "gts://gts.x.core.events.type.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object"
+                }),
+            )
+            .unwrap();
+
+        let content = json!({
+            "$id": "gts://gts.x.core.events.type.v1~x.core.audit.event.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "allOf": [
+                {"$ref": "gts://gts.x.core.events.type.v1~"},
+                {
+                    "properties": {
+                        "topic": {"$ref": "gts://gts.x.core.events.topic.v1~"},
+                        "local": {"$ref": "#/$defs/Local"}
+                    }
+                }
+            ]
+        });
+        let cfg = GtsConfig::default();
+        let entity = entity_from(&content, &cfg);
+
+        assert_eq!(
+            entity.validate_all_refs(&mut store),
+            vec!["gts.x.core.events.topic.v1~"]
+        );
+    }
 }
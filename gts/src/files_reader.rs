@@ -12,26 +12,71 @@ const VALID_EXTENSIONS: &[&str] = &[".json", ".jsonc", ".gts", ".yaml", ".yml"];
 pub struct GtsFileReader {
     paths: Vec<PathBuf>,
     cfg: GtsConfig,
+    /// Lowercase file name suffixes (with leading `.`) of the files to read.
+    extensions: Vec<String>,
     files: Vec<PathBuf>,
     initialized: bool,
 }
 
 impl GtsFileReader {
+    /// Creates a reader for `.json`, `.jsonc`, `.gts`, `.yaml` and `.yml` files under `path`.
     #[must_use]
     pub fn new(path: &[String], cfg: Option<GtsConfig>) -> Self {
-        let paths = path
+        Self::from_paths_with_extensions(path, VALID_EXTENSIONS, cfg)
+    }
+
+    /// Creates a reader that only reads files whose name ends with one of `extensions`.
+    ///
+    /// Extensions are matched case-insensitively against the end of the file name, so
+    /// compound extensions such as `.schema.json` are supported. The leading `.` is optional.
+    #[must_use]
+    pub fn from_paths_with_extensions(
+        paths: &[String],
+        extensions: &[&str],
+        cfg: Option<GtsConfig>,
+    ) -> Self {
+        let paths = paths
             .iter()
             .map(|p| PathBuf::from(shellexpand::tilde(p).to_string()))
             .collect();
+        let extensions = extensions
+            .iter()
+            .map(|ext| {
+                let ext = ext.to_lowercase();
+                if ext.starts_with('.') {
+                    ext
+                } else {
+                    format!(".{ext}")
+                }
+            })
+            .collect();
 
         GtsFileReader {
             paths,
             cfg: cfg.unwrap_or_default(),
+            extensions,
             files: Vec::new(),
             initialized: false,
         }
     }
 
+    /// Reads every entity from the configured paths, sorted by ID.
+    #[must_use]
+    pub fn into_entities(mut self) -> Vec<GtsEntity> {
+        let mut entities: Vec<GtsEntity> = self.iter().collect();
+        entities.sort_by_key(GtsEntity::effective_id);
+        entities
+    }
+
+    fn has_valid_extension(&self, path: &Path) -> bool {
+        path.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy().to_lowercase();
+            self.extensions
+                .iter()
+                .any(|ext| name.ends_with(ext.as_str()))
+        })
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn collect_files(&mut self) {
         let mut seen = std::collections::HashSet::new();
@@ -41,15 +86,12 @@ impl GtsFileReader {
             let resolved_path = path.canonicalize().unwrap_or_else(|_| path.clone());
 
             if resolved_path.is_file() {
-                if let Some(ext) = resolved_path.extension() {
-                    let ext_str = ext.to_string_lossy().to_lowercase();
-                    if VALID_EXTENSIONS.contains(&format!(".{ext_str}").as_str()) {
-                        let rp = resolved_path.to_string_lossy().to_string();
-                        if !seen.contains(&rp) {
-                            seen.insert(rp.clone());
-                            tracing::debug!("- discovered file: {:?}", resolved_path);
-                            collected.push(resolved_path.clone());
-                        }
+                if self.has_valid_extension(&resolved_path) {
+                    let rp = resolved_path.to_string_lossy().to_string();
+                    if !seen.contains(&rp) {
+                        seen.insert(rp.clone());
+                        tracing::debug!("- discovered file: {:?}", resolved_path);
+                        collected.push(resolved_path.clone());
                     }
                 }
             } else if resolved_path.is_dir() {
@@ -68,21 +110,16 @@ impl GtsFileReader {
                         continue;
                     }
 
-                    if path.is_file()
-                        && let Some(ext) = path.extension()
-                    {
-                        let ext_str = ext.to_string_lossy().to_lowercase();
-                        if VALID_EXTENSIONS.contains(&format!(".{ext_str}").as_str()) {
-                            let rp = path
-                                .canonicalize()
-                                .unwrap_or_else(|_| path.to_path_buf())
-                                .to_string_lossy()
-                                .to_string();
-                            if !seen.contains(&rp) {
-                                seen.insert(rp.clone());
-                                tracing::debug!("- discovered file: {:?}", path);
-                                collected.push(PathBuf::from(rp));
-                            }
+                    if path.is_file() && self.has_valid_extension(path) {
+                        let rp = path
+                            .canonicalize()
+                            .unwrap_or_else(|_| path.to_path_buf())
+                            .to_string_lossy()
+                            .to_string();
+                        if !seen.contains(&rp) {
+                            seen.insert(rp.clone());
+                            tracing::debug!("- discovered file: {:?}", path);
+                            collected.push(PathBuf::from(rp));
                         }
                     }
                 }
@@ -303,6 +340,34 @@ mod tests {
         assert_eq!(reader.files.len(), 0);
     }
 
+    #[test]
+    fn test_from_paths_with_extensions() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("event.schema.json"), r#"{"$id": "s"}"#).unwrap();
+        fs::write(
+            temp_dir.path().join("EVENT2.SCHEMA.JSON"),
+            r#"{"$id": "s2"}"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("event.json"), r#"{"id": "i"}"#).unwrap();
+        fs::write(temp_dir.path().join("event.yaml"), "id: i2").unwrap();
+
+        let paths = vec![temp_dir.path().to_string_lossy().to_string()];
+        let mut reader = GtsFileReader::from_paths_with_extensions(&paths, &[".schema.json"], None);
+        reader.collect_files();
+        assert_eq!(reader.files.len(), 2);
+        assert!(
+            reader
+                .files
+                .iter()
+                .all(|f| f.to_string_lossy().to_lowercase().ends_with(".schema.json"))
+        );
+
+        let mut reader = GtsFileReader::from_paths_with_extensions(&paths, &["yaml"], None);
+        reader.collect_files();
+        assert_eq!(reader.files.len(), 1);
+    }
+
     #[test]
     fn test_collect_files_directory() {
         let temp_dir = TempDir::new().unwrap();
@@ -578,6 +643,37 @@ mod tests {
         assert_eq!(entities.len(), 2);
     }
 
+    #[test]
+    fn test_into_entities_sorted_by_id() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("a.json"),
+            r#"[{"id": "gts.x.test.reader.item.v1~x.test.reader.zeta.v1"}, {"id": "gts.x.test.reader.item.v1~x.test.reader.beta.v1"}]"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("b.json"),
+            r#"{"id": "gts.x.test.reader.item.v1~x.test.reader.alpha.v1"}"#,
+        )
+        .unwrap();
+
+        let paths = vec![temp_dir.path().to_string_lossy().to_string()];
+        let ids: Vec<String> = GtsFileReader::new(&paths, None)
+            .into_entities()
+            .iter()
+            .filter_map(GtsEntity::effective_id)
+            .collect();
+
+        assert_eq!(
+            ids,
+            vec![
+                "gts.x.test.reader.item.v1~x.test.reader.alpha.v1",
+                "gts.x.test.reader.item.v1~x.test.reader.beta.v1",
+                "gts.x.test.reader.item.v1~x.test.reader.zeta.v1",
+            ]
+        );
+    }
+
     #[test]
     fn test_read_by_id_always_returns_none() {
         let reader = GtsFileReader::new(&[], None);
@@ -8,6 +8,16 @@ pub const GTS_PREFIX: &str = "gts.";
 /// URI-compatible prefix for GTS identifiers in JSON Schema `$id` field (e.g., `gts://gts.x.y.z...`).
 /// This is ONLY used for JSON Schema serialization/deserialization, not for GTS ID parsing.
 pub const GTS_URI_PREFIX: &str = "gts://";
+
+/// Returns `value` without its `gts://` prefix, or `None` if it is not a GTS URI.
+///
+/// This is the single place that knows how `$id` and `$ref` values map to canonical IDs;
+/// see [`GtsID::from_uri`] and [`GtsID::to_uri`] for the validated forms.
+#[must_use]
+pub fn strip_gts_uri(value: &str) -> Option<&str> {
+    value.strip_prefix(GTS_URI_PREFIX)
+}
+
 static GTS_NS: LazyLock<Uuid> = LazyLock::new(|| Uuid::new_v5(&Uuid::NAMESPACE_URL, b"gts"));
 
 /// Validates a GTS segment token without regex for better performance.
@@ -42,6 +52,9 @@ pub enum GtsError {
 
     #[error("Invalid GTS wildcard pattern: {pattern}: {cause}")]
     Wildcard { pattern: String, cause: String },
+
+    #[error("Invalid GTS attribute path: {input}: {cause}")]
+    InvalidPathSyntax { input: String, cause: String },
 }
 
 /// Parsed GTS segment containing vendor, package, namespace, type, and version info.
@@ -56,6 +69,7 @@ pub struct GtsIdSegment {
     pub type_name: String,
     pub ver_major: u32,
     pub ver_minor: Option<u32>,
+    pub ver_patch: Option<u32>,
     pub is_type: bool,
     pub is_wildcard: bool,
 }
@@ -77,6 +91,7 @@ impl GtsIdSegment {
             type_name: String::new(),
             ver_major: 0,
             ver_minor: None,
+            ver_patch: None,
             is_type: false,
             is_wildcard: false,
         };
@@ -85,6 +100,54 @@ impl GtsIdSegment {
         Ok(seg)
     }
 
+    /// Builds a segment from a string already known to be a valid, non-wildcard segment.
+    ///
+    /// Tokens are taken positionally and versions read as-is; nothing is checked.
+    fn new_unchecked(num: usize, offset: usize, segment: &str) -> Self {
+        let (body, is_type) = match segment.strip_suffix('~') {
+            Some(body) => (body, true),
+            None => (segment, false),
+        };
+        let mut tokens = body.split('.');
+        let mut next_token = || tokens.next().unwrap_or_default();
+        let vendor = next_token().to_owned();
+        let package = next_token().to_owned();
+        let namespace = next_token().to_owned();
+        let type_name = next_token().to_owned();
+        let ver_major = next_token()
+            .strip_prefix('v')
+            .and_then(|major| major.parse().ok())
+            .unwrap_or(0);
+        let ver_minor = next_token().parse().ok();
+        let ver_patch = next_token().parse().ok();
+
+        GtsIdSegment {
+            num,
+            offset,
+            segment: segment.to_owned(),
+            vendor,
+            package,
+            namespace,
+            type_name,
+            ver_major,
+            ver_minor,
+            ver_patch,
+            is_type,
+            is_wildcard: false,
+        }
+    }
+
+    /// Whether the segment carries a major version (wildcards may stop before it).
+    fn has_version(&self) -> bool {
+        !self.is_wildcard
+            || self
+                .segment
+                .trim_end_matches('~')
+                .split('.')
+                .nth(4)
+                .is_some_and(|token| token != "*")
+    }
+
     #[allow(clippy::too_many_lines)]
     fn parse_segment_id(&mut self, segment: &str) -> Result<(), GtsError> {
         let mut segment = segment.to_owned();
@@ -115,7 +178,7 @@ impl GtsIdSegment {
 
         let tokens: Vec<&str> = segment.split('.').collect();
 
-        if tokens.len() > 6 {
+        if tokens.len() > 7 {
             return Err(GtsError::Segment {
                 num: self.num,
                 offset: self.offset,
@@ -238,10 +301,83 @@ impl GtsIdSegment {
             self.ver_minor = Some(minor);
         }
 
+        if tokens.len() > 6 {
+            if tokens[6] == "*" {
+                self.is_wildcard = true;
+                return Ok(());
+            }
+
+            let patch: u32 = tokens[6].parse().map_err(|_| GtsError::Segment {
+                num: self.num,
+                offset: self.offset,
+                segment: self.segment.clone(),
+                cause: "Patch version must be an integer".to_owned(),
+            })?;
+
+            if tokens[6] != patch.to_string() {
+                return Err(GtsError::Segment {
+                    num: self.num,
+                    offset: self.offset,
+                    segment: self.segment.clone(),
+                    cause: "Patch version must be an integer".to_owned(),
+                });
+            }
+
+            self.ver_patch = Some(patch);
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the segment as `vendor.package.namespace.type.vMAJOR[.MINOR[.PATCH]]`, without
+/// the trailing `~`. Wildcard segments are written as parsed.
+impl fmt::Display for GtsIdSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_wildcard {
+            return write!(f, "{}", self.segment.trim_end_matches('~'));
+        }
+        write!(
+            f,
+            "{}.{}.{}.{}.v{}",
+            self.vendor, self.package, self.namespace, self.type_name, self.ver_major
+        )?;
+        if let Some(minor) = self.ver_minor {
+            write!(f, ".{minor}")?;
+        }
+        if let Some(patch) = self.ver_patch {
+            write!(f, ".{patch}")?;
+        }
         Ok(())
     }
 }
 
+impl PartialOrd for GtsIdSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders segments by vendor, package, namespace and type name, then by version
+/// (a missing minor or patch sorts first). The remaining fields only break ties.
+impl Ord for GtsIdSegment {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.vendor
+            .cmp(&other.vendor)
+            .then_with(|| self.package.cmp(&other.package))
+            .then_with(|| self.namespace.cmp(&other.namespace))
+            .then_with(|| self.type_name.cmp(&other.type_name))
+            .then_with(|| self.ver_major.cmp(&other.ver_major))
+            .then_with(|| self.ver_minor.cmp(&other.ver_minor))
+            .then_with(|| self.ver_patch.cmp(&other.ver_patch))
+            .then_with(|| self.is_type.cmp(&other.is_type))
+            .then_with(|| self.is_wildcard.cmp(&other.is_wildcard))
+            .then_with(|| self.segment.cmp(&other.segment))
+            .then_with(|| self.num.cmp(&other.num))
+            .then_with(|| self.offset.cmp(&other.offset))
+    }
+}
+
 /// GTS ID - a validated Global Type System identifier.
 ///
 /// GTS IDs follow the format: `gts.<vendor>.<package>.<namespace>.<type>.<version>[~]`
@@ -339,11 +475,103 @@ impl GtsID {
         })
     }
 
+    /// Builds a `GtsID` from a string that is already known to be a valid GTS identifier,
+    /// such as a key read back from a `GtsStore`, skipping all validation.
+    ///
+    /// The ID is split on `~` and `.` and each segment's tokens are taken positionally;
+    /// none of the checks done by [`GtsID::new`] run.
+    ///
+    /// **The caller must guarantee that `GtsID::new(id)` would succeed.** Any other input,
+    /// including wildcard patterns, yields a `GtsID` whose segment data is meaningless.
+    #[must_use]
+    pub fn new_unchecked(id: &str) -> Self {
+        let mut offset = GTS_PREFIX.len();
+        let gts_id_segments = id
+            .strip_prefix(GTS_PREFIX)
+            .unwrap_or(id)
+            .split_inclusive('~')
+            .enumerate()
+            .map(|(i, part)| {
+                let segment = GtsIdSegment::new_unchecked(i + 1, offset, part);
+                offset += part.len();
+                segment
+            })
+            .collect();
+
+        GtsID {
+            id: id.to_owned(),
+            gts_id_segments,
+        }
+    }
+
     #[must_use]
     pub fn is_type(&self) -> bool {
         self.id.ends_with('~')
     }
 
+    /// Whether this ID names a schema, i.e. its last segment is a type (ends with `~`).
+    #[must_use]
+    pub fn is_schema(&self) -> bool {
+        self.gts_id_segments.last().is_some_and(|s| s.is_type)
+    }
+
+    /// Whether this ID names an instance, i.e. its last segment is not a type.
+    #[must_use]
+    pub fn is_instance(&self) -> bool {
+        !self.is_schema()
+    }
+
+    /// The `(major, minor)` version of the primary segment (the last one for chained IDs).
+    ///
+    /// Returns `None` for wildcard patterns that stop before the version.
+    #[must_use]
+    pub fn version(&self) -> Option<(u32, Option<u32>)> {
+        let segment = self.gts_id_segments.last()?;
+        segment
+            .has_version()
+            .then_some((segment.ver_major, segment.ver_minor))
+    }
+
+    /// The only segment of a non-chained ID; `None` for chained IDs.
+    fn single_segment(&self) -> Option<&GtsIdSegment> {
+        match self.gts_id_segments.as_slice() {
+            [segment] => Some(segment),
+            _ => None,
+        }
+    }
+
+    /// Vendor of a single-segment ID. Returns `None` for chained IDs or when the field is wildcarded.
+    #[must_use]
+    pub fn vendor(&self) -> Option<&str> {
+        self.single_segment()
+            .map(|s| s.vendor.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Package of a single-segment ID. Returns `None` for chained IDs or when the field is wildcarded.
+    #[must_use]
+    pub fn package(&self) -> Option<&str> {
+        self.single_segment()
+            .map(|s| s.package.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Namespace of a single-segment ID. Returns `None` for chained IDs or when the field is wildcarded.
+    #[must_use]
+    pub fn namespace(&self) -> Option<&str> {
+        self.single_segment()
+            .map(|s| s.namespace.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Type name of a single-segment ID. Returns `None` for chained IDs or when the field is wildcarded.
+    #[must_use]
+    pub fn type_name(&self) -> Option<&str> {
+        self.single_segment()
+            .map(|s| s.type_name.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
     #[must_use]
     pub fn get_type_id(&self) -> Option<String> {
         if self.gts_id_segments.len() < 2 {
@@ -363,6 +591,25 @@ impl GtsID {
         Uuid::new_v5(&GTS_NS, self.id.as_bytes())
     }
 
+    /// Returns the `gts://` URI form of this ID, as used in schema `$id` and `$ref`.
+    #[must_use]
+    pub fn to_uri(&self) -> String {
+        format!("{GTS_URI_PREFIX}{}", self.id)
+    }
+
+    /// Parses a GTS ID from its `gts://` URI form.
+    ///
+    /// # Errors
+    /// Returns `GtsError::Id` if `uri` does not start with `gts://` or the rest is not a
+    /// valid GTS ID.
+    pub fn from_uri(uri: &str) -> Result<Self, GtsError> {
+        let id = strip_gts_uri(uri.trim()).ok_or_else(|| GtsError::Id {
+            id: uri.to_owned(),
+            cause: format!("Does not start with '{GTS_URI_PREFIX}'"),
+        })?;
+        Self::new(id)
+    }
+
     /// Check if a string is a valid GTS identifier.
     #[must_use]
     pub fn is_valid(s: &str) -> bool {
@@ -373,6 +620,9 @@ impl GtsID {
     }
 
     /// Check if this GTS ID matches a wildcard pattern.
+    ///
+    /// A trailing wildcard accepts everything after it, so `base~*` matches every
+    /// descendant of `base~` at any nesting depth, but not `base~` itself.
     #[must_use]
     pub fn wildcard_match(&self, pattern: &GtsWildcard) -> bool {
         let p = &pattern.id;
@@ -421,6 +671,11 @@ impl GtsID {
                 {
                     return false;
                 }
+                if let Some(p_patch) = p_seg.ver_patch
+                    && Some(p_patch) != c_seg.ver_patch
+                {
+                    return false;
+                }
                 if p_seg.is_type && p_seg.is_type != c_seg.is_type {
                     return false;
                 }
@@ -454,6 +709,13 @@ impl GtsID {
                 return false;
             }
 
+            // Patch version: same rule as minor
+            if let Some(p_patch) = p_seg.ver_patch
+                && Some(p_patch) != c_seg.ver_patch
+            {
+                return false;
+            }
+
             // Check is_type flag matches
             if p_seg.is_type != c_seg.is_type {
                 return false;
@@ -465,27 +727,46 @@ impl GtsID {
 
     /// Splits a GTS ID with an optional attribute path.
     ///
+    /// `@` is the attribute separator and takes precedence: the path follows the first
+    /// `@`, so later `@` characters belong to the path. A schema ID (ending in `~`) may
+    /// use `#` instead, e.g. `gts.x.core.events.type.v1~#properties.name.type`; on
+    /// instance IDs `#` is not a separator and stays part of the ID.
+    ///
     /// # Errors
-    /// Returns `GtsError::Id` if the path is empty after the `@` separator.
+    /// Returns `GtsError::InvalidPathSyntax` if the input mixes `@` and `#` or contains
+    /// more than one `#`, and `GtsError::Id` if the path is empty after the separator.
     pub fn split_at_path(gts_with_path: &str) -> Result<(String, Option<String>), GtsError> {
-        if !gts_with_path.contains('@') {
-            return Ok((gts_with_path.to_owned(), None));
+        let hashes = gts_with_path.matches('#').count();
+        if hashes > 0 && gts_with_path.contains('@') {
+            return Err(GtsError::InvalidPathSyntax {
+                input: gts_with_path.to_owned(),
+                cause: "Use either '@' or '#' as the path separator, not both".to_owned(),
+            });
+        }
+        if hashes > 1 {
+            return Err(GtsError::InvalidPathSyntax {
+                input: gts_with_path.to_owned(),
+                cause: "Only one '#' path separator is allowed".to_owned(),
+            });
         }
 
-        let parts: Vec<&str> = gts_with_path.splitn(2, '@').collect();
-        let gts = parts[0].to_owned();
-        let path = parts.get(1).map(|s| (*s).to_owned());
+        let split = gts_with_path.split_once('@').or_else(|| {
+            gts_with_path
+                .split_once('#')
+                .filter(|(gts, _)| gts.ends_with('~'))
+        });
+        let Some((gts, path)) = split else {
+            return Ok((gts_with_path.to_owned(), None));
+        };
 
-        if let Some(ref p) = path
-            && p.is_empty()
-        {
+        if path.is_empty() {
             return Err(GtsError::Id {
                 id: gts_with_path.to_owned(),
                 cause: "Attribute path cannot be empty".to_owned(),
             });
         }
 
-        Ok((gts, path))
+        Ok((gts.to_owned(), Some(path.to_owned())))
     }
 }
 
@@ -509,6 +790,25 @@ impl AsRef<str> for GtsID {
     }
 }
 
+impl serde::Serialize for GtsID {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.id)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GtsID {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        GtsID::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 /// GTS Wildcard pattern
 #[derive(Debug, Clone, PartialEq)]
 pub struct GtsWildcard {
@@ -729,6 +1029,49 @@ impl GtsInstanceId {
         Self(GtsEntityId::new(&format!("{schema_id}{segment}")))
     }
 
+    /// Returns the schema portion of the ID, up to and including the last `~`
+    /// (e.g., `gts.x.core.events.type.v1~`). Empty if the ID has no `~`.
+    #[must_use]
+    pub fn schema_id(&self) -> &str {
+        let id: &str = self.as_ref();
+        id.rfind('~').map_or("", |pos| &id[..=pos])
+    }
+
+    /// Parses the schema portion of the ID into a `GtsID`.
+    ///
+    /// # Errors
+    /// Returns `GtsError` if the schema portion is not a valid GTS schema ID.
+    pub fn schema_gts_id(&self) -> Result<GtsID, GtsError> {
+        GtsID::new(self.schema_id())
+    }
+
+    /// Parses the full chained ID (schema prefix plus instance segment) into a `GtsID`.
+    ///
+    /// # Errors
+    /// Returns `GtsError` if the combined ID is not a valid GTS identifier.
+    pub fn to_gts_id(&self) -> Result<GtsID, GtsError> {
+        GtsID::new(self.as_ref())
+    }
+
+    /// Checks that the ID is a well-formed GTS instance ID.
+    ///
+    /// [`GtsInstanceId::new`] accepts any segment; call this to verify the appended
+    /// segment has valid vendor/package/namespace/type/version tokens.
+    ///
+    /// # Errors
+    /// Returns the `GtsError` from parsing the full ID, or `GtsError::Id` if the ID
+    /// names a schema (ends with `~`) rather than an instance.
+    pub fn validate(&self) -> Result<(), GtsError> {
+        let id = self.to_gts_id()?;
+        if id.is_schema() {
+            return Err(GtsError::Id {
+                id: self.to_string(),
+                cause: "Instance ID must not end with '~'".to_owned(),
+            });
+        }
+        Ok(())
+    }
+
     /// Returns the underlying string representation of the instance ID.
     #[must_use]
     pub fn into_string(self) -> String {
@@ -1222,6 +1565,175 @@ mod tests {
         assert_eq!(id.gts_id_segments[0].ver_minor, Some(999));
     }
 
+    #[test]
+    fn test_gts_id_new_unchecked_matches_new() {
+        for id in [
+            "gts.x.core.events.event.v1~",
+            "gts.x.core.events.type.v1~x.app.auth.login.v1.2",
+            "gts.x.core.events.type.v1~vendor.app._.custom.v1.2.3~",
+        ] {
+            assert_eq!(GtsID::new_unchecked(id), GtsID::new(id).expect("test"));
+        }
+    }
+
+    #[test]
+    fn test_gts_id_new_unchecked_skips_validation() {
+        // Upper case and a single-segment instance are both rejected by `new`
+        for id in ["gts.X.core.events.event.v1~", "gts.x.core.events.event.v1"] {
+            assert!(GtsID::new(id).is_err());
+            let gid = GtsID::new_unchecked(id);
+            assert_eq!(gid.id, id);
+            assert_eq!(gid.gts_id_segments.len(), 1);
+        }
+        assert_eq!(
+            GtsID::new_unchecked("gts.X.core.events.event.v1~").gts_id_segments[0].vendor,
+            "X"
+        );
+    }
+
+    #[test]
+    fn test_gts_id_is_schema_and_is_instance() {
+        let schema = GtsID::new("gts.x.core.events.type.v1~x.app.auth.login.v1~").expect("test");
+        assert!(schema.is_schema());
+        assert!(!schema.is_instance());
+
+        let instance = GtsID::new("gts.x.core.events.type.v1~x.app.auth.login.v1").expect("test");
+        assert!(!instance.is_schema());
+        assert!(instance.is_instance());
+    }
+
+    #[test]
+    fn test_gts_id_version() {
+        let id = GtsID::new("gts.x.core.events.event.v2~").expect("test");
+        assert_eq!(id.version(), Some((2, None)));
+
+        let chained = GtsID::new("gts.x.core.events.type.v1~x.app.auth.login.v3.4").expect("test");
+        assert_eq!(chained.version(), Some((3, Some(4))));
+
+        let wildcard = GtsID::new("gts.x.core.events.*").expect("test");
+        assert_eq!(wildcard.version(), None);
+
+        let versioned_wildcard = GtsID::new("gts.x.core.events.event.v0.*").expect("test");
+        assert_eq!(versioned_wildcard.version(), Some((0, None)));
+    }
+
+    #[test]
+    fn test_gts_instance_id_to_gts_id() {
+        let id = GtsInstanceId::new(
+            "gts.x.core.events.topic.v1~",
+            "vendor.app.orders.topic.v1.0",
+        );
+        let gts_id = id.to_gts_id().expect("test");
+        assert_eq!(
+            gts_id.id,
+            "gts.x.core.events.topic.v1~vendor.app.orders.topic.v1.0"
+        );
+        assert_eq!(gts_id.gts_id_segments.len(), 2);
+        assert_eq!(gts_id.gts_id_segments[1].vendor, "vendor");
+
+        let invalid = GtsInstanceId::new("gts.x.core.events.topic.v1~", "Not-Valid");
+        assert!(invalid.to_gts_id().is_err());
+    }
+
+    #[test]
+    fn test_gts_instance_id_schema_id() {
+        let id = GtsInstanceId::new(
+            "gts.x.core.events.topic.v1~",
+            "vendor.app.orders.topic.v1.0",
+        );
+        assert_eq!(id.schema_id(), "gts.x.core.events.topic.v1~");
+        let schema = id.schema_gts_id().expect("test");
+        assert!(schema.is_schema());
+        assert_eq!(schema.id, "gts.x.core.events.topic.v1~");
+
+        let chained = GtsInstanceId::new(
+            "gts.x.core.events.type.v1~x.core.audit.event.v1~",
+            "vendor.app.orders.topic.v1",
+        );
+        assert_eq!(
+            chained.schema_id(),
+            "gts.x.core.events.type.v1~x.core.audit.event.v1~"
+        );
+
+        let no_schema = GtsInstanceId::new("", "vendor.app.orders.topic.v1");
+        assert_eq!(no_schema.schema_id(), "");
+        assert!(no_schema.schema_gts_id().is_err());
+    }
+
+    #[test]
+    fn test_gts_instance_id_validate() {
+        let id = GtsInstanceId::new(
+            "gts.x.core.events.topic.v1~",
+            "vendor.app.orders.topic.v1.0",
+        );
+        assert!(id.validate().is_ok());
+
+        let missing_tokens =
+            GtsInstanceId::new("gts.x.core.events.topic.v1~", "vendor.app.orders.v1.0");
+        assert!(missing_tokens.validate().is_err());
+
+        let bad_version =
+            GtsInstanceId::new("gts.x.core.events.topic.v1~", "vendor.app.orders.topic.1");
+        assert!(bad_version.validate().is_err());
+
+        let schema =
+            GtsInstanceId::new("gts.x.core.events.topic.v1~", "vendor.app.orders.topic.v1~");
+        let err = schema.validate().unwrap_err();
+        assert!(err.to_string().contains("must not end with '~'"));
+    }
+
+    #[test]
+    fn test_gts_id_segment_accessors() {
+        let id = GtsID::new("gts.x.core.events.event.v1~").expect("test");
+        assert_eq!(id.vendor(), Some("x"));
+        assert_eq!(id.package(), Some("core"));
+        assert_eq!(id.namespace(), Some("events"));
+        assert_eq!(id.type_name(), Some("event"));
+
+        let chained = GtsID::new("gts.x.core.events.type.v1~x.app.auth.login.v1").expect("test");
+        assert_eq!(chained.vendor(), None);
+        assert_eq!(chained.package(), None);
+        assert_eq!(chained.namespace(), None);
+        assert_eq!(chained.type_name(), None);
+
+        let wildcard = GtsID::new("gts.x.core.*").expect("test");
+        assert_eq!(wildcard.package(), Some("core"));
+        assert_eq!(wildcard.namespace(), None);
+    }
+
+    #[test]
+    fn test_gts_id_version_with_patch() {
+        let id = GtsID::new("gts.x.core.events.event.v1.2.3~").expect("test");
+        let seg = &id.gts_id_segments[0];
+        assert_eq!(seg.ver_major, 1);
+        assert_eq!(seg.ver_minor, Some(2));
+        assert_eq!(seg.ver_patch, Some(3));
+
+        let id = GtsID::new("gts.x.core.events.event.v1.2~").expect("test");
+        assert_eq!(id.gts_id_segments[0].ver_patch, None);
+
+        let err = GtsID::new("gts.x.core.events.event.v1.2.x~").unwrap_err();
+        assert!(err.to_string().contains("Patch version must be an integer"));
+        assert!(GtsID::new("gts.x.core.events.event.v1.2.03~").is_err());
+    }
+
+    #[test]
+    fn test_gts_wildcard_patch_version() {
+        let id = GtsID::new("gts.x.core.events.event.v1.2.3~").expect("test");
+
+        let pattern = GtsWildcard::new("gts.x.core.events.event.v1.2~").expect("test");
+        assert!(id.wildcard_match(&pattern));
+
+        let pattern = GtsWildcard::new("gts.x.core.events.event.v1.2.3~").expect("test");
+        assert!(id.wildcard_match(&pattern));
+
+        let pattern = GtsWildcard::new("gts.x.core.events.event.v1.2.4~").expect("test");
+        assert!(!id.wildcard_match(&pattern));
+
+        let pattern = GtsWildcard::new("gts.x.core.events.event.v1.2.*").expect("test");
+        assert!(id.wildcard_match(&pattern));
+    }
+
     #[test]
     fn test_gts_wildcard_no_wildcard_different_vendor() {
         let pattern = GtsWildcard::new("gts.x.core.events.event.v1~").expect("test");
@@ -1237,10 +1749,34 @@ mod tests {
 
     #[test]
     fn test_split_at_path_with_hash() {
-        // Hash is not a separator, should be part of the ID
+        // Hash separates a path on schema IDs
         let (gts, path) = GtsID::split_at_path("gts.x.core.events.event.v1~#field").expect("test");
-        assert_eq!(gts, "gts.x.core.events.event.v1~#field");
+        assert_eq!(gts, "gts.x.core.events.event.v1~");
+        assert_eq!(path, Some("field".to_owned()));
+
+        // On instance IDs it is not a separator and stays part of the ID
+        let (gts, path) =
+            GtsID::split_at_path("gts.x.core.events.event.v1~a.b.c.d.v1#field").expect("test");
+        assert_eq!(gts, "gts.x.core.events.event.v1~a.b.c.d.v1#field");
         assert_eq!(path, None);
+
+        assert!(GtsID::split_at_path("gts.x.core.events.event.v1~#").is_err());
+    }
+
+    #[test]
+    fn test_split_at_path_ambiguous_separators() {
+        for input in [
+            "gts.x.core.events.event.v1~#field@subfield",
+            "gts.x.core.events.event.v1~@field#subfield",
+            "gts.x.core.events.event.v1~#field#subfield",
+            "gts.x.core.events.event.v1~a.b.c.d.v1#a#b",
+        ] {
+            let err = GtsID::split_at_path(input).unwrap_err();
+            assert!(
+                matches!(err, GtsError::InvalidPathSyntax { input: ref i, .. } if i == input),
+                "{input}: {err}"
+            );
+        }
     }
 
     #[test]
@@ -1340,6 +1876,83 @@ mod tests {
         assert!(!id2.wildcard_match(&pattern));
     }
 
+    #[test]
+    fn test_gts_id_segment_display() {
+        let seg = GtsIdSegment::new(0, 0, "x.core.events.event.v1.2~").expect("test");
+        assert_eq!(seg.to_string(), "x.core.events.event.v1.2");
+        let seg = GtsIdSegment::new(0, 0, "x.core.events.event.v3").expect("test");
+        assert_eq!(seg.to_string(), "x.core.events.event.v3");
+        let seg = GtsIdSegment::new(0, 0, "x.core.*").expect("test");
+        assert_eq!(seg.to_string(), "x.core.*");
+    }
+
+    #[test]
+    fn test_gts_id_segment_ordering() {
+        let mut segs: Vec<GtsIdSegment> = [
+            "x.core.events.event.v2~",
+            "x.core.events.event.v1.1~",
+            "a.core.events.event.v9~",
+            "x.core.events.event.v1~",
+            "x.core.events.alert.v1~",
+        ]
+        .iter()
+        .map(|s| GtsIdSegment::new(0, 0, s).expect("test"))
+        .collect();
+        segs.sort();
+        let sorted: Vec<String> = segs.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "a.core.events.event.v9",
+                "x.core.events.alert.v1",
+                "x.core.events.event.v1",
+                "x.core.events.event.v1.1",
+                "x.core.events.event.v2",
+            ]
+        );
+
+        let a = GtsIdSegment::new(0, 0, "x.core.events.event.v1~").expect("test");
+        let b = GtsIdSegment::new(1, 20, "x.core.events.event.v1~").expect("test");
+        assert!(a < b);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_gts_id_uri_round_trip() {
+        let id = GtsID::new("gts.x.core.events.event.v1~").expect("test");
+        assert_eq!(id.to_uri(), "gts://gts.x.core.events.event.v1~");
+        assert_eq!(GtsID::from_uri(&id.to_uri()).expect("test"), id);
+
+        assert!(matches!(
+            GtsID::from_uri("gts.x.core.events.event.v1~"),
+            Err(GtsError::Id { .. })
+        ));
+        assert!(GtsID::from_uri("gts://not-a-gts-id").is_err());
+
+        assert_eq!(
+            strip_gts_uri("gts://gts.x.core.events.event.v1~"),
+            Some("gts.x.core.events.event.v1~")
+        );
+        assert_eq!(strip_gts_uri("gts.x.core.events.event.v1~"), None);
+    }
+
+    #[test]
+    fn test_gts_wildcard_type_suffix_matches_any_depth() {
+        let pattern = GtsWildcard::new("gts.x.pkg.ns.type.v1~*").expect("test");
+        for id in [
+            "gts.x.pkg.ns.type.v1~a.b.c.child.v1~",
+            "gts.x.pkg.ns.type.v1~a.b.c.child.v1~d.e.f.grandchild.v1~",
+            "gts.x.pkg.ns.type.v1~a.b.c.child.v1~d.e.f.grandchild.v1~g.h.i.item.v1",
+        ] {
+            assert!(
+                GtsID::new(id).expect("test").wildcard_match(&pattern),
+                "{id}"
+            );
+        }
+        let base = GtsID::new("gts.x.pkg.ns.type.v1~").expect("test");
+        assert!(!base.wildcard_match(&pattern));
+    }
+
     #[test]
     fn test_split_at_path_valid_json_pointer() {
         let (gts, path) = GtsID::split_at_path("gts.x.test.v1~@/properties/field").expect("test");
@@ -1382,8 +1995,8 @@ mod tests {
 
     #[test]
     fn test_gts_segment_too_many_tokens() {
-        // More than 6 tokens in a segment
-        let seg = GtsIdSegment::new(1, 0, "x.pkg.ns.type.v1.2.extra~");
+        // More than 7 tokens (vendor.package.namespace.type.vMAJOR.MINOR.PATCH)
+        let seg = GtsIdSegment::new(1, 0, "x.pkg.ns.type.v1.2.3.extra~");
         assert!(seg.is_err());
         if let Err(e) = seg {
             assert!(e.to_string().contains("Too many tokens"));
@@ -0,0 +1,257 @@
+//! [`GtsReader`] backed by a remote registry, enabled with the `http-reader` feature.
+//!
+//! The registry must serve `GET {base_url}/entities` with the list of entity IDs and
+//! `GET {base_url}/entities/{id}` with an entity's content. Both the plain shapes
+//! (an array of IDs, the raw entity JSON) and the `gts server` response envelopes
+//! are understood.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::entities::{GtsConfig, GtsEntity};
+use crate::store::GtsReader;
+
+pub struct HttpGtsReader {
+    base_url: String,
+    cfg: GtsConfig,
+    agent: ureq::Agent,
+    /// Entities already fetched, so that `read_by_id` only hits the network on a miss.
+    cache: Mutex<HashMap<String, GtsEntity>>,
+}
+
+impl HttpGtsReader {
+    #[must_use]
+    pub fn new(base_url: &str, cfg: GtsConfig) -> Self {
+        HttpGtsReader {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            cfg,
+            agent: ureq::Agent::new_with_defaults(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get_json(&self, path: &str) -> Option<Value> {
+        let url = format!("{}{path}", self.base_url);
+        let body = match self.agent.get(&url).call() {
+            Ok(mut response) => response.body_mut().read_to_string(),
+            Err(e) => {
+                tracing::debug!("HttpGtsReader: GET {url} failed: {e}");
+                return None;
+            }
+        };
+        match body.map(|b| serde_json::from_str(&b)) {
+            Ok(Ok(value)) => Some(value),
+            Ok(Err(e)) => {
+                tracing::debug!("HttpGtsReader: invalid JSON from {url}: {e}");
+                None
+            }
+            Err(e) => {
+                tracing::debug!("HttpGtsReader: failed to read body from {url}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Extracts the entity IDs from a `GET /entities` response.
+    fn parse_entity_ids(body: &Value) -> Vec<String> {
+        let items = match body {
+            Value::Array(items) => items,
+            Value::Object(map) => match map.get("entities") {
+                Some(Value::Array(items)) => items,
+                _ => return Vec::new(),
+            },
+            _ => return Vec::new(),
+        };
+        items
+            .iter()
+            .filter_map(|item| match item {
+                Value::String(id) => Some(id.clone()),
+                Value::Object(obj) => obj.get("id").and_then(Value::as_str).map(str::to_owned),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Extracts the entity content from a `GET /entities/{id}` response.
+    fn parse_entity_content(body: Value) -> Option<Value> {
+        if let Some(ok) = body.get("ok").and_then(Value::as_bool) {
+            // `gts server` envelope: {"ok": ..., "content": ...}
+            if !ok {
+                return None;
+            }
+            return body.get("content").cloned();
+        }
+        Some(body)
+    }
+
+    fn fetch_entity(&self, entity_id: &str) -> Option<GtsEntity> {
+        let body = self.get_json(&format!("/entities/{entity_id}"))?;
+        let content = Self::parse_entity_content(body)?;
+        let entity = GtsEntity::new(
+            None,
+            None,
+            &content,
+            Some(&self.cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        entity.effective_id().is_some().then_some(entity)
+    }
+
+    fn cache_entity(&self, entity_id: &str, entity: &GtsEntity) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(entity_id.to_owned(), entity.clone());
+        }
+    }
+}
+
+impl GtsReader for HttpGtsReader {
+    fn iter(&mut self) -> Box<dyn Iterator<Item = GtsEntity> + '_> {
+        let ids = self
+            .get_json("/entities")
+            .map(|body| Self::parse_entity_ids(&body))
+            .unwrap_or_default();
+
+        tracing::debug!(
+            "HttpGtsReader: fetching {} entities from {}",
+            ids.len(),
+            self.base_url
+        );
+
+        let entities: Vec<GtsEntity> = ids
+            .iter()
+            .filter_map(|id| {
+                let entity = self.fetch_entity(id)?;
+                self.cache_entity(id, &entity);
+                Some(entity)
+            })
+            .collect();
+
+        Box::new(entities.into_iter())
+    }
+
+    fn read_by_id(&self, entity_id: &str) -> Option<GtsEntity> {
+        if let Ok(cache) = self.cache.lock()
+            && let Some(entity) = cache.get(entity_id)
+        {
+            return Some(entity.clone());
+        }
+
+        let entity = self.fetch_entity(entity_id)?;
+        self.cache_entity(entity_id, &entity);
+        Some(entity)
+    }
+
+    fn reset(&mut self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    const SCHEMA_ID: &str = "gts.x.test.http.user.v1~";
+    const INSTANCE_ID: &str = "gts.x.test.http.user.v1~x.test.http.alice.v1";
+
+    /// Serves the registry routes on a local port, counting the requests it handles.
+    fn start_registry() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+                let (status, body) = match path.strip_prefix("/entities") {
+                    Some("") => (
+                        "200 OK",
+                        json!({"entities": [{"id": SCHEMA_ID}, {"id": INSTANCE_ID}]}),
+                    ),
+                    Some(id) if id == format!("/{SCHEMA_ID}") => (
+                        "200 OK",
+                        json!({
+                            "$id": format!("gts://{SCHEMA_ID}"),
+                            "$schema": "http://json-schema.org/draft-07/schema#",
+                            "type": "object"
+                        }),
+                    ),
+                    Some(id) if id == format!("/{INSTANCE_ID}") => (
+                        "200 OK",
+                        json!({"ok": true, "id": INSTANCE_ID, "content": {"id": INSTANCE_ID}}),
+                    ),
+                    _ => ("404 Not Found", json!({"ok": false, "error": "not found"})),
+                };
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test_parse_entity_ids_shapes() {
+        assert_eq!(
+            HttpGtsReader::parse_entity_ids(&json!(["a", "b"])),
+            vec!["a".to_owned(), "b".to_owned()]
+        );
+        assert_eq!(
+            HttpGtsReader::parse_entity_ids(&json!({"entities": [{"id": "a"}], "count": 1})),
+            vec!["a".to_owned()]
+        );
+        assert!(HttpGtsReader::parse_entity_ids(&json!({"error": "x"})).is_empty());
+    }
+
+    #[test]
+    fn test_iter_and_read_by_id() {
+        let (base_url, requests) = start_registry();
+        let mut reader = HttpGtsReader::new(&format!("{base_url}/"), GtsConfig::default());
+
+        let mut ids: Vec<String> = reader.iter().filter_map(|e| e.effective_id()).collect();
+        ids.sort();
+        assert_eq!(ids, vec![SCHEMA_ID.to_owned(), INSTANCE_ID.to_owned()]);
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // Served from the cache filled by `iter`
+        assert!(reader.read_by_id(INSTANCE_ID).unwrap().gts_id.is_some());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        reader.reset();
+        assert!(reader.read_by_id(SCHEMA_ID).unwrap().is_schema);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        assert!(reader.read_by_id("gts.x.test.http.missing.v1~").is_none());
+    }
+}
@@ -0,0 +1,20 @@
+//! Metrics for `GtsOps` operations, recorded through the `metrics` facade.
+//!
+//! Enabled with the `metrics` feature; without it, recording is a no-op.
+//! Each operation emits `gts.<op>.calls` (labelled `result = ok | error`) and
+//! `gts.<op>.duration_ms`.
+
+use std::time::Instant;
+
+/// Record one call of `op` that started at `started`.
+#[cfg(feature = "metrics")]
+pub fn record(op: &'static str, ok: bool, started: Instant) {
+    let result = if ok { "ok" } else { "error" };
+    metrics::counter!(format!("gts.{op}.calls"), "result" => result).increment(1);
+    metrics::histogram!(format!("gts.{op}.duration_ms"))
+        .record(started.elapsed().as_secs_f64() * 1000.0);
+}
+
+/// Record one call of `op` that started at `started`.
+#[cfg(not(feature = "metrics"))]
+pub fn record(_op: &'static str, _ok: bool, _started: Instant) {}
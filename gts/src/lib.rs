@@ -1,20 +1,36 @@
+#[cfg(feature = "async-store")]
+pub mod async_store;
 pub mod entities;
 pub mod files_reader;
 pub mod gts;
+#[cfg(feature = "http-reader")]
+pub mod http_reader;
+mod instrumentation;
 pub mod ops;
 pub mod path_resolver;
 pub mod schema;
 pub mod schema_cast;
 pub mod store;
+#[cfg(feature = "gts-wasm")]
+pub mod wasm;
 pub mod x_gts_ref;
 
 // Re-export commonly used types
-pub use entities::{GtsConfig, GtsEntity, GtsFile, ValidationError, ValidationResult};
+#[cfg(feature = "async-store")]
+pub use async_store::{AsyncGtsReader, AsyncGtsStore};
+pub use entities::{
+    ContentDiff, GtsConfig, GtsConfigBuilder, GtsEntity, GtsFile, ValidationError, ValidationResult,
+};
 pub use files_reader::GtsFileReader;
 pub use gts::{GtsError, GtsID, GtsIdSegment, GtsInstanceId, GtsSchemaId, GtsWildcard};
-pub use ops::GtsOps;
+#[cfg(feature = "http-reader")]
+pub use http_reader::HttpGtsReader;
+pub use ops::{GraphNode, GtsOps};
 pub use path_resolver::JsonPathResolver;
-pub use schema::{GtsSchema, strip_schema_metadata};
-pub use schema_cast::{GtsEntityCastResult, SchemaCastError};
-pub use store::{GtsReader, GtsStore, GtsStoreQueryResult, StoreError};
+pub use schema::{GtsSchema, strip_schema_metadata, wrap_in_nesting_path};
+pub use schema_cast::{GtsEntityCastResult, MigrationLanguage, SchemaCastError};
+pub use store::{
+    CompactReport, GtsReader, GtsStore, GtsStoreQueryResult, QueryOptions, StoreError,
+    StoreListener,
+};
 pub use x_gts_ref::{XGtsRefValidationError, XGtsRefValidator};
@@ -1,15 +1,19 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::fs;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use crate::entities::{GtsConfig, GtsEntity};
 use crate::files_reader::GtsFileReader;
-use crate::gts::{GtsID, GtsWildcard};
+use crate::gts::{GtsError, GtsID, GtsWildcard, strip_gts_uri};
+use crate::instrumentation;
 use crate::path_resolver::JsonPathResolver;
 use crate::schema_cast::GtsEntityCastResult;
-use crate::store::{GtsStore, GtsStoreQueryResult};
+use crate::store::{GtsStore, GtsStoreQueryResult, QueryOptions, StoreError};
+use crate::x_gts_ref::XGtsRefValidator;
 
 /// `is_schema` is `Some(true)` for schema/type IDs (ending with `~`),
 /// `Some(false)` for instance IDs, and `None` when the input couldn't be
@@ -36,6 +40,8 @@ pub struct GtsIdSegmentInfo {
     pub type_name: String,
     pub ver_major: Option<u32>,
     pub ver_minor: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ver_patch: Option<u32>,
     pub is_type: bool,
 }
 
@@ -48,11 +54,45 @@ impl From<&crate::gts::GtsIdSegment> for GtsIdSegmentInfo {
             type_name: seg.type_name.clone(),
             ver_major: Some(seg.ver_major),
             ver_minor: seg.ver_minor,
+            ver_patch: seg.ver_patch,
             is_type: seg.is_type,
         }
     }
 }
 
+impl GtsIdSegmentInfo {
+    /// Rebuilds the `GtsIdSegment` these fields describe.
+    ///
+    /// The segment text is reassembled and parsed again, so every field is validated;
+    /// `num` and `offset` are 0 since the info does not record them.
+    ///
+    /// # Errors
+    /// Returns `GtsError::Segment` if `ver_major` is missing or the fields do not form a
+    /// valid segment (e.g. an empty vendor).
+    pub fn to_segment(&self) -> Result<crate::gts::GtsIdSegment, GtsError> {
+        let name = format!(
+            "{}.{}.{}.{}",
+            self.vendor, self.package, self.namespace, self.type_name
+        );
+        let Some(major) = self.ver_major else {
+            return Err(GtsError::Segment {
+                num: 0,
+                offset: 0,
+                segment: name,
+                cause: "Missing major version".to_owned(),
+            });
+        };
+        let optional = |v: Option<u32>| v.map(|v| format!(".{v}")).unwrap_or_default();
+        let segment = format!(
+            "{name}.v{major}{}{}{}",
+            optional(self.ver_minor),
+            optional(self.ver_patch),
+            if self.is_type { "~" } else { "" }
+        );
+        crate::gts::GtsIdSegment::new(0, 0, &segment)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsIdParseResult {
     pub id: String,
@@ -65,6 +105,27 @@ pub struct GtsIdParseResult {
     pub is_wildcard: bool,
 }
 
+impl GtsIdParseResult {
+    /// Converts a successful parse back into a [`GtsID`].
+    ///
+    /// # Errors
+    /// Returns `GtsError::Id` with the recorded parse error if parsing failed, or if
+    /// the input was a wildcard pattern rather than a concrete ID.
+    pub fn to_gts_id(&self) -> Result<GtsID, GtsError> {
+        let cause = if !self.ok {
+            self.error.clone()
+        } else if self.is_wildcard {
+            "Wildcard patterns cannot be converted to a GTS ID".to_owned()
+        } else {
+            return GtsID::new(&self.id);
+        };
+        Err(GtsError::Id {
+            id: self.id.clone(),
+            cause,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsIdMatchResult {
     pub candidate: String,
@@ -96,6 +157,79 @@ pub struct GtsSchemaGraphResult {
     pub graph: Value,
 }
 
+/// A node of a schema graph, as listed by [`GtsSchemaGraphResult::nodes`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub is_schema: bool,
+    /// Distance from the root of the graph.
+    pub depth: usize,
+    /// Number of entities this node references.
+    pub ref_count: usize,
+    /// Number of registered instances whose schema is this node.
+    pub instance_count: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl GtsSchemaGraphResult {
+    /// Flattens the graph into its nodes in depth-first order, starting at the root.
+    ///
+    /// A node reached more than once (e.g. through a reference cycle) is listed each time;
+    /// truncated markers from [`GtsOps::schema_graph_with_depth`] are skipped.
+    #[must_use]
+    pub fn nodes(&self) -> Vec<GraphNode> {
+        let mut nodes = Vec::new();
+        Self::collect_nodes(&self.graph, &mut nodes);
+        nodes
+    }
+
+    fn collect_nodes(node: &Value, nodes: &mut Vec<GraphNode>) {
+        let Some(id) = node.get("id").and_then(Value::as_str) else {
+            return;
+        };
+        if node.get("truncated").and_then(Value::as_bool) == Some(true) {
+            return;
+        }
+        let count = |field: &str| {
+            node.get(field)
+                .and_then(Value::as_u64)
+                .and_then(|n| usize::try_from(n).ok())
+                .unwrap_or_default()
+        };
+        nodes.push(GraphNode {
+            id: id.to_owned(),
+            is_schema: node
+                .get("is_schema")
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+            depth: count("depth"),
+            ref_count: count("ref_count"),
+            instance_count: count("instance_count"),
+            errors: node
+                .get("errors")
+                .and_then(Value::as_array)
+                .map(|errors| {
+                    errors
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        });
+
+        if let Some(refs) = node.get("refs").and_then(Value::as_object) {
+            for child in refs.values() {
+                Self::collect_nodes(child, nodes);
+            }
+        }
+        if let Some(schema) = node.get("schema_id") {
+            Self::collect_nodes(schema, nodes);
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsEntityInfo {
     pub id: String,
@@ -126,6 +260,7 @@ pub struct GtsEntitiesListResult {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsAddEntityResult {
     pub ok: bool,
+    /// Effective ID of the entity; also set on failure when the ID could be detected.
     #[serde(skip_serializing_if = "String::is_empty")]
     pub id: String,
     pub schema_id: Option<String>,
@@ -158,6 +293,72 @@ pub struct GtsExtractIdResult {
     pub is_schema: bool,
 }
 
+/// A reference from an instance to a GTS ID that is not registered or does not
+/// satisfy its `x-gts-ref` constraint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityError {
+    pub instance_id: String,
+    pub field_path: String,
+    pub unresolved_ref_id: String,
+}
+
+/// Summary of a schema's properties, computed from its resolved content.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GtsSchemaStats {
+    pub schema_id: String,
+    pub total_properties: usize,
+    pub required_properties: usize,
+    pub optional_properties: usize,
+    /// Property name to its JSON Schema `type` (`"a|b"` for type unions, `"any"` when untyped).
+    pub property_types: HashMap<String, String>,
+    pub has_additional_properties: bool,
+    pub description: Option<String>,
+    /// Number of inheritance levels, i.e. segments in the chained schema ID.
+    pub depth: usize,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub error: String,
+}
+
+/// An ordered sequence of casts that migrates instances between two versions of a schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationPlan {
+    pub from_id: String,
+    pub to_id: String,
+    /// Consecutive `(from_id, to_id)` cast operations, in the order they must be applied.
+    pub steps: Vec<(String, String)>,
+}
+
+/// One entity in a file written by [`GtsOps::export_to_file`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ExportedEntity {
+    id: String,
+    is_schema: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    schema_id: Option<String>,
+    content: Value,
+}
+
+/// `(major, minor, patch)` version of a schema segment.
+type SchemaVersion = (u32, Option<u32>, Option<u32>);
+
+/// Splits a schema ID into its version-less family key and its `(major, minor, patch)` version.
+fn schema_version_key(schema_id: &str) -> Option<(String, SchemaVersion)> {
+    let gid = GtsID::new(schema_id).ok()?;
+    let last = gid.gts_id_segments.last()?;
+    if !last.is_type || last.is_wildcard {
+        return None;
+    }
+    let family = format!(
+        "{}{}.{}.{}.{}",
+        &gid.id[..last.offset],
+        last.vendor,
+        last.package,
+        last.namespace,
+        last.type_name
+    );
+    Some((family, (last.ver_major, last.ver_minor, last.ver_patch)))
+}
+
 pub struct GtsOps {
     pub verbose: usize,
     pub cfg: GtsConfig,
@@ -168,7 +369,12 @@ pub struct GtsOps {
 impl GtsOps {
     #[must_use]
     pub fn new(path: Option<Vec<String>>, config: Option<String>, verbose: usize) -> Self {
-        let cfg = Self::load_config(config);
+        Self::new_with_config(path, Self::load_config(config), verbose)
+    }
+
+    /// Creates a `GtsOps` with an already-built config, without reading any config file.
+    #[must_use]
+    pub fn new_with_config(path: Option<Vec<String>>, cfg: GtsConfig, verbose: usize) -> Self {
         let reader: Option<Box<dyn crate::store::GtsReader>> = path.as_ref().map(|p| {
             Box::new(GtsFileReader::new(p, Some(cfg.clone()))) as Box<dyn crate::store::GtsReader>
         });
@@ -185,14 +391,14 @@ impl GtsOps {
     fn load_config(config_path: Option<String>) -> GtsConfig {
         // Try user-provided path
         if let Some(path) = config_path
-            && let Ok(cfg) = Self::load_config_from_path(&PathBuf::from(path))
+            && let Ok(cfg) = GtsConfig::from_file(&PathBuf::from(path))
         {
             return cfg;
         }
 
         // Try default path (relative to current directory)
         let default_path = PathBuf::from("gts.config.json");
-        if let Ok(cfg) = Self::load_config_from_path(&default_path) {
+        if let Ok(cfg) = GtsConfig::from_file(&default_path) {
             return cfg;
         }
 
@@ -200,41 +406,6 @@ impl GtsOps {
         GtsConfig::default()
     }
 
-    fn load_config_from_path(path: &PathBuf) -> Result<GtsConfig, Box<dyn std::error::Error>> {
-        let content = fs::read_to_string(path)?;
-        let data: HashMap<String, Value> = serde_json::from_str(&content)?;
-        Ok(Self::create_config_from_data(&data))
-    }
-
-    fn create_config_from_data(data: &HashMap<String, Value>) -> GtsConfig {
-        let default_cfg = GtsConfig::default();
-
-        let entity_id_fields = data
-            .get("entity_id_fields")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(ToString::to_string))
-                    .collect()
-            })
-            .unwrap_or(default_cfg.entity_id_fields);
-
-        let schema_id_fields = data
-            .get("schema_id_fields")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(ToString::to_string))
-                    .collect()
-            })
-            .unwrap_or(default_cfg.schema_id_fields);
-
-        GtsConfig {
-            entity_id_fields,
-            schema_id_fields,
-        }
-    }
-
     pub fn reload_from_path(&mut self, path: &[String]) {
         self.path = Some(path.to_vec());
         let reader = Box::new(GtsFileReader::new(path, Some(self.cfg.clone())))
@@ -262,11 +433,59 @@ impl GtsOps {
         }
     }
 
+    /// Canonicalizes a user-supplied GTS ID.
+    ///
+    /// Trims whitespace, strips the `gts://` prefix and lowercases the ID before
+    /// validating it.
+    ///
+    /// # Errors
+    /// Returns `GtsError` if the result is not a valid GTS ID.
+    pub fn normalize_id(raw: &str) -> Result<String, GtsError> {
+        Self::parse_normalized_id(raw).map(|(gid, _)| gid.id)
+    }
+
+    /// Parses a user-supplied GTS ID as [`GtsOps::normalize_id`] does, also reporting
+    /// whether it was written as a `gts://` URI.
+    fn parse_normalized_id(raw: &str) -> Result<(GtsID, bool), GtsError> {
+        let lowered = raw.trim().to_lowercase();
+        let (id, is_uri) = match strip_gts_uri(&lowered) {
+            Some(id) => (id, true),
+            None => (lowered.as_str(), false),
+        };
+        Ok((GtsID::new(id.trim())?, is_uri))
+    }
+
+    /// Normalizes the top-level ID fields of `content` that hold a GTS ID, keeping
+    /// the `gts://` prefix where it was present. Other values are left untouched.
+    fn normalize_id_fields(&self, content: &Value) -> Value {
+        let mut content = content.clone();
+        let Some(obj) = content.as_object_mut() else {
+            return content;
+        };
+        for field in self
+            .cfg
+            .entity_id_fields
+            .iter()
+            .chain(&self.cfg.schema_id_fields)
+        {
+            let Some(Value::String(raw)) = obj.get(field) else {
+                continue;
+            };
+            let Ok((gid, is_uri)) = Self::parse_normalized_id(raw) else {
+                continue;
+            };
+            let normalized = if is_uri { gid.to_uri() } else { gid.id };
+            obj.insert(field.clone(), Value::String(normalized));
+        }
+        content
+    }
+
     pub fn add_entity(&mut self, content: &Value, validate: bool) -> GtsAddEntityResult {
+        let content = self.normalize_id_fields(content);
         let entity = GtsEntity::new(
             None,
             None,
-            content,
+            &content,
             Some(&self.cfg),
             None,
             false,
@@ -301,7 +520,7 @@ impl GtsOps {
         if let Err(e) = self.store.register(entity.clone()) {
             return GtsAddEntityResult {
                 ok: false,
-                id: String::new(),
+                id: entity_id.clone(),
                 schema_id: None,
                 is_schema: false,
                 error: format!(
@@ -317,7 +536,7 @@ impl GtsOps {
         {
             return GtsAddEntityResult {
                 ok: false,
-                id: String::new(),
+                id: entity_id.clone(),
                 schema_id: None,
                 is_schema: false,
                 error: format!(
@@ -332,13 +551,25 @@ impl GtsOps {
             && !entity.is_schema
             && let Err(e) = self.store.validate_instance(&entity_id)
         {
+            // The store falls back to its reader, so a missing schema is one neither
+            // registered nor readable
+            let cause = match &e {
+                StoreError::SchemaNotFound(schema_id)
+                    if entity.schema_id.as_ref() == Some(schema_id) =>
+                {
+                    format!(
+                        "schema '{schema_id}' is not registered and could not be loaded from the reader"
+                    )
+                }
+                _ => e.to_string(),
+            };
             return GtsAddEntityResult {
                 ok: false,
-                id: String::new(),
-                schema_id: None,
+                id: entity_id.clone(),
+                schema_id: entity.schema_id.clone(),
                 is_schema: false,
                 error: format!(
-                    "Instance validation failed: {e}\n{}",
+                    "Instance validation failed: {cause}\n{}",
                     self.get_details(&entity)
                 ),
             };
@@ -362,6 +593,45 @@ impl GtsOps {
         GtsAddEntitiesResult { ok, results }
     }
 
+    /// Like [`GtsOps::add_entities`], but validates instances in parallel.
+    ///
+    /// All entities are registered first, one at a time, so that instances can see
+    /// schemas from the same batch; when `validate` is set, the registered instances
+    /// are then validated in parallel. As with [`GtsOps::add_entity`], an instance
+    /// that fails validation stays registered.
+    pub fn bulk_add_entities(&mut self, items: &[Value], validate: bool) -> GtsAddEntitiesResult {
+        let mut results: Vec<GtsAddEntityResult> =
+            items.iter().map(|it| self.add_entity(it, false)).collect();
+
+        if validate {
+            let (indices, ids): (Vec<usize>, Vec<String>) = results
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| r.ok && !r.is_schema)
+                .map(|(i, r)| (i, r.id.clone()))
+                .unzip();
+            let outcomes = self.store.validate_instances(&ids);
+            for ((index, id), outcome) in indices.into_iter().zip(&ids).zip(outcomes) {
+                if let Err(e) = outcome {
+                    let (schema_id, details) = match self.store.get_cached(id).cloned() {
+                        Some(entity) => (entity.schema_id.clone(), self.get_details(&entity)),
+                        None => (None, String::new()),
+                    };
+                    results[index] = GtsAddEntityResult {
+                        ok: false,
+                        id: id.clone(),
+                        schema_id,
+                        is_schema: false,
+                        error: format!("Instance validation failed: {e}\n{details}"),
+                    };
+                }
+            }
+        }
+
+        let ok = results.iter().all(|r| r.ok);
+        GtsAddEntitiesResult { ok, results }
+    }
+
     pub fn add_schema(&mut self, type_id: String, schema: &Value) -> GtsAddSchemaResult {
         match self.store.register_schema(&type_id, schema) {
             Ok(()) => GtsAddSchemaResult {
@@ -421,7 +691,7 @@ impl GtsOps {
                     id: gts_id.to_owned(),
                     valid: true,
                     error: String::new(),
-                    is_schema: Some(id.is_type()),
+                    is_schema: Some(id.is_schema()),
                     is_wildcard: false,
                 },
                 Err(e) => GtsIdValidationResult {
@@ -480,7 +750,7 @@ impl GtsOps {
                         ok: true,
                         segments,
                         error: String::new(),
-                        is_schema: Some(id.is_type()),
+                        is_schema: Some(id.is_schema()),
                         is_wildcard: false,
                     }
                 }
@@ -560,7 +830,9 @@ impl GtsOps {
     }
 
     pub fn validate_instance(&mut self, gts_id: &str) -> GtsValidationResult {
-        match self.store.validate_instance(gts_id) {
+        let started = Instant::now();
+        let normalized = Self::normalize_id(gts_id).unwrap_or_else(|_| gts_id.to_owned());
+        let result = match self.store.validate_instance(&normalized) {
             Ok(()) => GtsValidationResult {
                 id: gts_id.to_owned(),
                 ok: true,
@@ -571,7 +843,9 @@ impl GtsOps {
                 ok: false,
                 error: e.to_string(),
             },
-        }
+        };
+        instrumentation::record("validate", result.ok, started);
+        result
     }
 
     pub fn validate_schema(&mut self, gts_id: &str) -> GtsValidationResult {
@@ -602,6 +876,17 @@ impl GtsOps {
         GtsSchemaGraphResult { graph }
     }
 
+    /// Like [`GtsOps::schema_graph`], but stops at `max_depth` levels below the root;
+    /// deeper nodes are replaced with `{"id": ..., "truncated": true}`.
+    pub fn schema_graph_with_depth(
+        &mut self,
+        gts_id: &str,
+        max_depth: usize,
+    ) -> GtsSchemaGraphResult {
+        let graph = self.store.build_schema_graph_with_depth(gts_id, max_depth);
+        GtsSchemaGraphResult { graph }
+    }
+
     pub fn compatibility(
         &mut self,
         old_schema_id: &str,
@@ -611,7 +896,8 @@ impl GtsOps {
     }
 
     pub fn cast(&mut self, from_id: &str, to_schema_id: &str) -> GtsEntityCastResult {
-        match self.store.cast(from_id, to_schema_id) {
+        let started = Instant::now();
+        let result = match self.store.cast(from_id, to_schema_id) {
             Ok(result) => result,
             Err(e) => GtsEntityCastResult {
                 from_id: from_id.to_owned(),
@@ -629,21 +915,385 @@ impl GtsOps {
                 backward_errors: Vec::new(),
                 forward_errors: Vec::new(),
                 casted_entity: None,
+                casted_entity_id: None,
                 error: Some(e.to_string()),
             },
+        };
+        instrumentation::record("cast", result.error.is_none(), started);
+        result
+    }
+
+    /// Like [`GtsOps::cast`], but also registers the casted entity in the store.
+    ///
+    /// A chained GTS ID gets its source schema prefix replaced by `to_schema_id`; an
+    /// anonymous instance keeps its ID. The new ID is returned in `casted_entity_id`.
+    pub fn cast_and_register(&mut self, from_id: &str, to_schema_id: &str) -> GtsEntityCastResult {
+        let mut result = self.cast(from_id, to_schema_id);
+        let Some(content) = result.casted_entity.clone() else {
+            return result;
+        };
+        let from_schema = self
+            .store
+            .get_cached(from_id)
+            .and_then(|entity| entity.schema_id.clone())
+            .unwrap_or_default();
+        match self
+            .store
+            .register_casted(from_id, &from_schema, to_schema_id, content)
+        {
+            Ok(new_id) => result.casted_entity_id = Some(new_id),
+            Err(e) => result.error = Some(e.to_string()),
         }
+        result
+    }
+
+    /// Checks that every `x-gts-ref` value in every instance resolves to a registered entity.
+    ///
+    /// Each instance is checked against its schema's `x-gts-ref` constraints, and every
+    /// referenced ID must also exist in the store. Instances whose schema cannot be
+    /// loaded are skipped.
+    pub fn check_referential_integrity(&mut self) -> Vec<IntegrityError> {
+        let validator = XGtsRefValidator::new();
+        let mut instance_ids: Vec<String> = self
+            .store
+            .items()
+            .filter(|(_, entity)| !entity.is_schema)
+            .map(|(id, _)| id.clone())
+            .collect();
+        instance_ids.sort();
+
+        let mut errors = Vec::new();
+        for instance_id in instance_ids {
+            let Some((content, Some(schema_id))) = self
+                .store
+                .get(&instance_id)
+                .map(|e| (e.content.clone(), e.schema_id.clone()))
+            else {
+                continue;
+            };
+            let Ok(schema) = self.store.get_schema_content(&schema_id) else {
+                continue;
+            };
+
+            let mut reported: Vec<String> = Vec::new();
+            for error in validator.validate_instance(&content, &schema, "") {
+                reported.push(error.field_path.clone());
+                errors.push(IntegrityError {
+                    instance_id: instance_id.clone(),
+                    field_path: error.field_path,
+                    unresolved_ref_id: error.value,
+                });
+            }
+
+            for (field_path, ref_id) in validator.referenced_ids(&content, &schema, "") {
+                if !reported.contains(&field_path) && self.store.get(&ref_id).is_none() {
+                    errors.push(IntegrityError {
+                        instance_id: instance_id.clone(),
+                        field_path,
+                        unresolved_ref_id: ref_id,
+                    });
+                }
+            }
+        }
+        errors
+    }
+
+    /// Summarizes the properties of a schema, including those inherited through `allOf` parents.
+    pub fn schema_stats(&mut self, schema_id: &str) -> GtsSchemaStats {
+        let mut stats = GtsSchemaStats {
+            schema_id: schema_id.to_owned(),
+            ..GtsSchemaStats::default()
+        };
+
+        let content = match self.store.get(schema_id) {
+            Some(entity) if entity.is_schema => entity.content.clone(),
+            _ => {
+                stats.error = StoreError::SchemaNotFound(schema_id.to_owned()).to_string();
+                return stats;
+            }
+        };
+
+        let flat = self.flatten_schema(&content);
+
+        if let Some(props) = flat.get("properties").and_then(Value::as_object) {
+            for (name, prop) in props {
+                let prop_type = match prop.get("type") {
+                    Some(Value::String(t)) => t.clone(),
+                    Some(Value::Array(types)) => types
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join("|"),
+                    _ => "any".to_owned(),
+                };
+                stats.property_types.insert(name.clone(), prop_type);
+            }
+        }
+
+        let mut required: Vec<&str> = flat
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter(|name| stats.property_types.contains_key(*name))
+            .collect();
+        required.sort_unstable();
+        required.dedup();
+
+        stats.total_properties = stats.property_types.len();
+        stats.required_properties = required.len();
+        stats.optional_properties = stats.total_properties - stats.required_properties;
+        stats.has_additional_properties = flat
+            .get("additionalProperties")
+            .is_none_or(|additional| additional != &Value::Bool(false));
+        stats.description = content
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        stats.depth = GtsID::new(schema_id).map_or(0, |gid| gid.gts_id_segments.len());
+        stats
+    }
+
+    /// Merges a schema's `allOf` parents into a single schema. Each `allOf` part is
+    /// resolved on its own so that `additionalProperties` of every level survives.
+    fn flatten_schema(&self, content: &Value) -> Value {
+        let mut resolved = content.clone();
+        if let Some(all_of) = resolved.get_mut("allOf").and_then(Value::as_array_mut) {
+            for part in all_of.iter_mut() {
+                *part = self.store.resolve_schema_refs(part);
+            }
+        }
+        GtsEntityCastResult::flatten_schema(&resolved)
+    }
+
+    /// Writes every registered entity to `path` as a JSON array, sorted by ID.
+    ///
+    /// # Errors
+    /// Returns an I/O error if the file cannot be created or written.
+    pub fn export_to_file(&self, path: &Path) -> Result<(), std::io::Error> {
+        let mut entities: Vec<ExportedEntity> = self
+            .store
+            .items()
+            .map(|(id, entity)| ExportedEntity {
+                id: id.clone(),
+                is_schema: entity.is_schema,
+                schema_id: entity.schema_id.clone(),
+                content: entity.content.clone(),
+            })
+            .collect();
+        entities.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, &entities)?;
+        writer.flush()
+    }
+
+    /// Registers every entity from a file written by [`GtsOps::export_to_file`].
+    ///
+    /// Returns the number of imported entities.
+    ///
+    /// # Errors
+    /// Returns `StoreError::ImportError` if the file cannot be read or parsed, and any
+    /// error returned while registering an entity.
+    pub fn import_from_file(&mut self, path: &Path) -> Result<usize, StoreError> {
+        let content =
+            fs::read_to_string(path).map_err(|e| StoreError::ImportError(e.to_string()))?;
+        let entities: Vec<ExportedEntity> =
+            serde_json::from_str(&content).map_err(|e| StoreError::ImportError(e.to_string()))?;
+
+        let count = entities.len();
+        for exported in entities {
+            if exported.is_schema {
+                self.store
+                    .register_schema(&exported.id, &exported.content)?;
+                continue;
+            }
+
+            let mut entity = GtsEntity::new(
+                None,
+                None,
+                &exported.content,
+                Some(&self.cfg),
+                GtsID::new(&exported.id).ok(),
+                false,
+                String::new(),
+                None,
+                exported.schema_id.clone(),
+            );
+            if entity.effective_id().is_none() {
+                entity.instance_id = Some(exported.id);
+            }
+            if entity.schema_id.is_none() {
+                entity.schema_id = exported.schema_id;
+            }
+            self.store.register(entity)?;
+        }
+        Ok(count)
+    }
+
+    /// Returns the IDs of registered schemas that have no registered instances, sorted.
+    #[must_use]
+    pub fn unused_schemas(&self) -> Vec<String> {
+        let mut unused: Vec<String> = self
+            .store
+            .items()
+            .filter(|(id, entity)| entity.is_schema && self.store.count_instances_of(id) == 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        unused.sort();
+        unused
+    }
+
+    /// Lists every registered version of a schema type, sorted by version.
+    ///
+    /// `type_path` is either a schema ID, whose version is ignored, or a glob such as
+    /// `gts.x.core.events.type.*~`. Unparseable input yields an empty list.
+    #[must_use]
+    pub fn version_history(&self, type_path: &str) -> Vec<String> {
+        let type_path = type_path.trim();
+        let mut versions: Vec<(SchemaVersion, String)> = if type_path.contains('*') {
+            let pattern = type_path.strip_suffix('~').unwrap_or(type_path);
+            let Ok(wildcard) = GtsWildcard::new(pattern) else {
+                return Vec::new();
+            };
+            self.store
+                .items()
+                .filter(|(_, entity)| entity.is_schema)
+                .filter_map(|(id, entity)| {
+                    let gid = entity.gts_id.as_ref()?;
+                    let (_, version) = schema_version_key(id)?;
+                    (gid.gts_id_segments.len() == wildcard.gts_id_segments.len()
+                        && gid.wildcard_match(&wildcard))
+                    .then(|| (version, id.clone()))
+                })
+                .collect()
+        } else {
+            let Some((family, _)) = schema_version_key(type_path) else {
+                return Vec::new();
+            };
+            self.store
+                .items()
+                .filter(|(_, entity)| entity.is_schema)
+                .filter_map(|(id, _)| {
+                    let (candidate_family, version) = schema_version_key(id)?;
+                    (candidate_family == family).then(|| (version, id.clone()))
+                })
+                .collect()
+        };
+        versions.sort();
+        versions.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Plans a migration between two versions of the same schema.
+    ///
+    /// Every registered version between `from_schema` and `to_schema` becomes an
+    /// intermediate hop, so the plan casts through each version in turn (upgrading
+    /// or downgrading depending on the direction).
+    ///
+    /// # Errors
+    /// Returns `StoreError::InvalidSchemaId` if either ID is not a schema ID,
+    /// `StoreError::SchemaNotFound` if either schema is not registered, and
+    /// `StoreError::ValidationError` if the schemas are not versions of the same type.
+    pub fn plan_migration(
+        &mut self,
+        from_schema: &str,
+        to_schema: &str,
+    ) -> Result<MigrationPlan, StoreError> {
+        let (from_family, _) =
+            schema_version_key(from_schema).ok_or(StoreError::InvalidSchemaId)?;
+        let (to_family, _) = schema_version_key(to_schema).ok_or(StoreError::InvalidSchemaId)?;
+
+        for id in [from_schema, to_schema] {
+            if !self.store.get(id).is_some_and(|e| e.is_schema) {
+                return Err(StoreError::SchemaNotFound(id.to_owned()));
+            }
+        }
+
+        if from_family != to_family {
+            return Err(StoreError::ValidationError(format!(
+                "Schemas '{from_schema}' and '{to_schema}' are not versions of the same type"
+            )));
+        }
+
+        let mut versions: Vec<(SchemaVersion, String)> = self
+            .store
+            .items()
+            .filter(|(_, entity)| entity.is_schema)
+            .filter_map(|(id, _)| {
+                let (family, version) = schema_version_key(id)?;
+                (family == from_family).then(|| (version, id.clone()))
+            })
+            .collect();
+        versions.sort();
+
+        let position = |id: &str| {
+            versions
+                .iter()
+                .position(|(_, v)| v == id)
+                .ok_or_else(|| StoreError::SchemaNotFound(id.to_owned()))
+        };
+        let start = position(from_schema)?;
+        let end = position(to_schema)?;
+
+        let mut path: Vec<&str> = versions[start.min(end)..=start.max(end)]
+            .iter()
+            .map(|(_, id)| id.as_str())
+            .collect();
+        if start > end {
+            path.reverse();
+        }
+
+        Ok(MigrationPlan {
+            from_id: from_schema.to_owned(),
+            to_id: to_schema.to_owned(),
+            steps: path
+                .windows(2)
+                .map(|pair| (pair[0].to_owned(), pair[1].to_owned()))
+                .collect(),
+        })
     }
 
     #[must_use]
     pub fn query(&self, expr: &str, limit: usize) -> GtsStoreQueryResult {
-        self.store.query(expr, limit)
+        let started = Instant::now();
+        let result = self.store.query(expr, limit);
+        instrumentation::record("query", result.error.is_empty(), started);
+        result
+    }
+
+    #[must_use]
+    pub fn query_with_options(&self, expr: &str, options: &QueryOptions) -> GtsStoreQueryResult {
+        let started = Instant::now();
+        let result = self.store.query_with_options(expr, options);
+        instrumentation::record("query", result.error.is_empty(), started);
+        result
+    }
+
+    /// Full-text search over entity content; see [`GtsStore::search`].
+    ///
+    /// An empty `fields` slice searches every field.
+    #[must_use]
+    pub fn search(&self, query: &str, fields: &[&str], limit: usize) -> GtsStoreQueryResult {
+        let started = Instant::now();
+        let result = self.store.search(query, fields, limit);
+        instrumentation::record("search", result.error.is_empty(), started);
+        result
     }
 
     pub fn attr(&mut self, gts_with_path: &str) -> JsonPathResolver {
         match GtsID::split_at_path(gts_with_path) {
             Ok((gts, Some(path))) => {
-                if let Some(entity) = self.store.get(&gts) {
-                    entity.resolve_path(&path)
+                if self.store.get(&gts).is_some()
+                    && let Some(entity) = self.store.get_cached(&gts)
+                {
+                    let direct = entity.resolve_path(&path);
+                    if direct.resolved || !entity.is_schema || !path.starts_with("properties") {
+                        return direct;
+                    }
+                    // Inherited `properties` of a derived schema only appear in the
+                    // effective schema
+                    JsonPathResolver::new(gts, self.flatten_schema(&entity.content)).resolve(&path)
                 } else {
                     JsonPathResolver::new(gts.clone(), Value::Null)
                         .failure(&path, &format!("Entity not found: {gts}"))
@@ -679,7 +1329,17 @@ impl GtsOps {
     }
 
     pub fn get_entity(&mut self, gts_id: &str) -> GtsGetEntityResult {
-        match self.store.get(gts_id) {
+        Self::get_entity_result(gts_id, self.store.get(gts_id))
+    }
+
+    /// Like [`GtsOps::get_entity`], but re-reads the entity from the reader instead of
+    /// returning a possibly stale cached copy, and updates the cache.
+    pub fn get_entity_fresh(&mut self, gts_id: &str) -> GtsGetEntityResult {
+        Self::get_entity_result(gts_id, self.store.refresh(gts_id))
+    }
+
+    fn get_entity_result(gts_id: &str, entity: Option<&GtsEntity>) -> GtsGetEntityResult {
+        match entity {
             Some(entity) => GtsGetEntityResult {
                 ok: true,
                 id: entity
@@ -737,6 +1397,7 @@ mod tests {
     use super::*;
     use crate::gts::GtsID;
     use serde_json::json;
+    use std::collections::HashMap;
 
     #[test]
     fn test_validate_id_valid() {
@@ -877,6 +1538,55 @@ mod tests {
         assert_eq!(result.to_id, "gts.test.derived.v1.1~");
     }
 
+    #[test]
+    fn test_cast_and_register() {
+        let mut ops = GtsOps::new(None, None, 0);
+        for (id, extra) in [
+            ("gts.x.test.castreg.user.v1.0~", json!({})),
+            (
+                "gts.x.test.castreg.user.v1.1~",
+                json!({"age": {"type": "integer", "default": 0}}),
+            ),
+        ] {
+            let mut properties = json!({"id": {"type": "string"}, "name": {"type": "string"}});
+            properties
+                .as_object_mut()
+                .expect("test")
+                .extend(extra.as_object().expect("test").clone());
+            let schema = json!({
+                "$id": format!("gts://{id}"),
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "properties": properties
+            });
+            assert!(ops.add_entity(&schema, false).ok);
+        }
+        let from_id = "gts.x.test.castreg.user.v1.0~x.test.castreg.alice.v1";
+        assert!(
+            ops.add_entity(&json!({"id": from_id, "name": "Alice"}), false)
+                .ok
+        );
+
+        let result = ops.cast_and_register(from_id, "gts.x.test.castreg.user.v1.1~");
+        assert!(result.error.is_none(), "{:?}", result.error);
+        let new_id = "gts.x.test.castreg.user.v1.1~x.test.castreg.alice.v1";
+        assert_eq!(result.casted_entity_id.as_deref(), Some(new_id));
+
+        let entity = ops.get_entity(new_id);
+        assert!(entity.ok, "{}", entity.error);
+        let content = entity.content.expect("test");
+        assert_eq!(content["id"], new_id);
+        assert_eq!(content["age"], 0);
+        assert!(ops.get_entity(from_id).ok);
+
+        let result = ops.cast_and_register(
+            "gts.x.test.castreg.user.v1.0~x.test.castreg.missing.v1",
+            "gts.x.test.castreg.user.v1.1~",
+        );
+        assert!(result.error.is_some());
+        assert!(result.casted_entity_id.is_none());
+    }
+
     #[test]
     fn test_resolve_path_simple() {
         use crate::path_resolver::JsonPathResolver;
@@ -1154,6 +1864,7 @@ mod tests {
             type_name: "type".to_owned(),
             ver_major: Some(1),
             ver_minor: Some(0),
+            ver_patch: None,
             is_type: false,
         };
 
@@ -1777,6 +2488,7 @@ mod tests {
             backward_errors: vec![],
             forward_errors: vec![],
             casted_entity: Some(json!({"name": "test"})),
+            casted_entity_id: None,
             error: None,
         };
 
@@ -2387,6 +3099,353 @@ mod tests {
         assert!(!result.gts_id.is_empty());
     }
 
+    #[test]
+    fn test_bulk_add_entities_validates_in_parallel() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let mut items = vec![json!({
+            "$id": "gts://gts.x.test.bulk.item.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["id", "size"],
+            "properties": {"id": {"type": "string"}, "size": {"type": "integer"}}
+        })];
+        for i in 0..20 {
+            items.push(
+                json!({"id": format!("gts.x.test.bulk.item.v1~x.test.bulk.n{i}.v1"), "size": i}),
+            );
+        }
+        items.push(json!({"id": "gts.x.test.bulk.item.v1~x.test.bulk.bad.v1", "size": "big"}));
+
+        let result = ops.bulk_add_entities(&items, true);
+        assert!(!result.ok);
+        assert_eq!(result.results.len(), 22);
+        assert!(result.results[..21].iter().all(|r| r.ok));
+        assert_eq!(
+            result.results[21].id,
+            "gts.x.test.bulk.item.v1~x.test.bulk.bad.v1"
+        );
+        assert!(
+            result.results[21]
+                .error
+                .starts_with("Instance validation failed")
+        );
+        assert_eq!(
+            result.results[21].schema_id.as_deref(),
+            Some("gts.x.test.bulk.item.v1~")
+        );
+        // Failed instances stay registered, as with add_entity
+        assert!(
+            ops.store
+                .get_cached("gts.x.test.bulk.item.v1~x.test.bulk.bad.v1")
+                .is_some()
+        );
+
+        let result = ops.bulk_add_entities(&items, false);
+        assert!(result.ok);
+    }
+
+    #[test]
+    fn test_get_entity_fresh_rereads_from_reader() {
+        use crate::store::GtsReader;
+        use std::sync::{Arc, Mutex};
+
+        struct SharedReader(Arc<Mutex<Value>>);
+
+        impl GtsReader for SharedReader {
+            fn iter(&mut self) -> Box<dyn Iterator<Item = GtsEntity> + '_> {
+                Box::new(self.read_by_id("").into_iter())
+            }
+
+            fn read_by_id(&self, _entity_id: &str) -> Option<GtsEntity> {
+                let content = self.0.lock().expect("test").clone();
+                let cfg = GtsConfig::default();
+                Some(GtsEntity::new(
+                    None,
+                    None,
+                    &content,
+                    Some(&cfg),
+                    None,
+                    false,
+                    String::new(),
+                    None,
+                    None,
+                ))
+            }
+
+            fn reset(&mut self) {}
+        }
+
+        let id = "gts.x.test.fresh.item.v1~x.test.fresh.a.v1";
+        let shared = Arc::new(Mutex::new(json!({"id": id, "rev": 1})));
+        let mut ops = GtsOps::new(None, None, 0);
+        ops.store = GtsStore::new(Some(Box::new(SharedReader(Arc::clone(&shared)))));
+
+        *shared.lock().expect("test") = json!({"id": id, "rev": 2});
+        assert_eq!(ops.get_entity(id).content.expect("test")["rev"], 1);
+        assert_eq!(ops.get_entity_fresh(id).content.expect("test")["rev"], 2);
+        // The cache now holds the refreshed copy
+        assert_eq!(ops.get_entity(id).content.expect("test")["rev"], 2);
+    }
+
+    #[test]
+    fn test_segment_info_to_segment() {
+        let id = GtsID::new("gts.x.core.events.event.v1.2~x.app.orders.created.v3").expect("test");
+        for seg in &id.gts_id_segments {
+            let rebuilt = GtsIdSegmentInfo::from(seg).to_segment().expect("test");
+            assert_eq!(rebuilt.segment, seg.segment);
+            assert_eq!(rebuilt.to_string(), seg.to_string());
+            assert_eq!(rebuilt.is_type, seg.is_type);
+        }
+
+        let mut info = GtsIdSegmentInfo::from(&id.gts_id_segments[0]);
+        info.vendor = String::new();
+        assert!(info.to_segment().is_err());
+
+        let mut info = GtsIdSegmentInfo::from(&id.gts_id_segments[0]);
+        info.ver_major = None;
+        assert!(matches!(
+            info.to_segment(),
+            Err(GtsError::Segment { ref cause, .. }) if cause == "Missing major version"
+        ));
+    }
+
+    #[test]
+    fn test_parse_result_to_gts_id() {
+        let id = "gts.x.test.parse.user.v1~x.test.parse.alice.v1";
+        let gts_id = GtsOps::parse_id(id).to_gts_id().expect("test");
+        assert_eq!(gts_id.id, id);
+        assert_eq!(gts_id.gts_id_segments.len(), 2);
+
+        let err = GtsOps::parse_id("not-a-gts-id").to_gts_id().unwrap_err();
+        assert!(matches!(err, GtsError::Id { ref id, .. } if id == "not-a-gts-id"));
+
+        assert!(GtsOps::parse_id("gts.x.test.parse.*").to_gts_id().is_err());
+    }
+
+    #[test]
+    fn test_add_entity_validate_loads_schema_from_reader() {
+        use crate::store::GtsReader;
+
+        struct SchemaReader(GtsEntity);
+
+        impl GtsReader for SchemaReader {
+            fn iter(&mut self) -> Box<dyn Iterator<Item = GtsEntity> + '_> {
+                Box::new(std::iter::empty())
+            }
+
+            fn read_by_id(&self, entity_id: &str) -> Option<GtsEntity> {
+                (self.0.gts_id.as_ref().map(|id| id.id.as_str()) == Some(entity_id))
+                    .then(|| self.0.clone())
+            }
+
+            fn reset(&mut self) {}
+        }
+
+        let schema = GtsEntity::new(
+            None,
+            None,
+            &json!({
+                "$id": "gts://gts.x.test.preload.user.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "required": ["name"],
+                "properties": {"name": {"type": "string"}}
+            }),
+            Some(&GtsConfig::default()),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        let mut ops = GtsOps::new(None, None, 0);
+        ops.store = GtsStore::new(Some(Box::new(SchemaReader(schema))));
+
+        let result = ops.add_entity(
+            &json!({"id": "gts.x.test.preload.user.v1~x.test.preload.alice.v1", "name": "Alice"}),
+            true,
+        );
+        assert!(result.ok, "{}", result.error);
+        assert!(
+            ops.store
+                .get_cached("gts.x.test.preload.user.v1~")
+                .is_some()
+        );
+
+        let result = ops.add_entity(
+            &json!({"id": "gts.x.test.preload.user.v1~x.test.preload.bob.v1"}),
+            true,
+        );
+        assert!(!result.ok);
+        assert!(result.error.contains("Instance validation failed"));
+        assert_eq!(
+            result.id,
+            "gts.x.test.preload.user.v1~x.test.preload.bob.v1"
+        );
+
+        let result = ops.add_entity(
+            &json!({"id": "gts.x.test.preload.other.v1~x.test.preload.carol.v1"}),
+            true,
+        );
+        assert!(!result.ok);
+        assert!(
+            result
+                .error
+                .contains("schema 'gts.x.test.preload.other.v1~' is not registered")
+        );
+        assert_eq!(
+            result.schema_id.as_deref(),
+            Some("gts.x.test.preload.other.v1~")
+        );
+    }
+
+    #[test]
+    fn test_schema_graph_nodes() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let base = json!({
+            "$id": "gts://gts.x.test.graph.event.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {"id": {"type": "string"}}
+        });
+        let derived = json!({
+            "$id": "gts://gts.x.test.graph.event.v1~x.test.graph.created.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "allOf": [{"$ref": "gts://gts.x.test.graph.event.v1~"}, {"type": "object"}]
+        });
+        assert!(ops.add_entity(&base, false).ok);
+        assert!(ops.add_entity(&derived, false).ok);
+        let peer = "gts.x.test.graph.event.v1~x.test.graph.created.v1~x.test.graph.b.v1";
+        assert!(ops.add_entity(&json!({"id": peer}), false).ok);
+        let instance = json!({
+            "id": "gts.x.test.graph.event.v1~x.test.graph.created.v1~x.test.graph.a.v1",
+            "peer": peer
+        });
+        assert!(ops.add_entity(&instance, false).ok);
+
+        let result =
+            ops.schema_graph("gts.x.test.graph.event.v1~x.test.graph.created.v1~x.test.graph.a.v1");
+        assert_eq!(result.graph["depth"], 0);
+        assert_eq!(result.graph["is_schema"], false);
+
+        let nodes = result.nodes();
+        assert_eq!(nodes.len(), 5);
+        assert_eq!(nodes[0].ref_count, 1);
+        assert_eq!(nodes[1].id, peer);
+        assert_eq!(nodes[1].depth, 1);
+
+        let derived_nodes: Vec<_> = nodes
+            .iter()
+            .filter(|n| n.id == "gts.x.test.graph.event.v1~x.test.graph.created.v1~")
+            .collect();
+        assert_eq!(derived_nodes.len(), 2);
+        assert!(
+            derived_nodes
+                .iter()
+                .all(|n| n.is_schema && n.instance_count == 2)
+        );
+        assert_eq!(derived_nodes.iter().map(|n| n.depth).min(), Some(1));
+
+        let base_node = nodes
+            .iter()
+            .find(|n| n.id == "gts.x.test.graph.event.v1~")
+            .expect("test");
+        assert_eq!(base_node.depth, 3);
+        assert_eq!(base_node.instance_count, 0);
+    }
+
+    #[test]
+    fn test_schema_graph_with_depth() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let base = json!({
+            "$id": "gts://gts.x.test.depth.event.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        });
+        let derived = json!({
+            "$id": "gts://gts.x.test.depth.event.v1~x.test.depth.created.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "allOf": [{"$ref": "gts://gts.x.test.depth.event.v1~"}, {"type": "object"}]
+        });
+        let instance_id = "gts.x.test.depth.event.v1~x.test.depth.created.v1~x.test.depth.a.v1";
+        assert!(ops.add_entity(&base, false).ok);
+        assert!(ops.add_entity(&derived, false).ok);
+        assert!(ops.add_entity(&json!({"id": instance_id}), false).ok);
+
+        let graph = ops.schema_graph_with_depth(instance_id, 1).graph;
+        let schema = &graph["schema_id"];
+        assert_eq!(
+            schema["id"],
+            "gts.x.test.depth.event.v1~x.test.depth.created.v1~"
+        );
+        assert_eq!(schema["depth"], 1);
+        assert_eq!(
+            schema["schema_id"],
+            json!({"id": "gts.x.test.depth.event.v1~", "truncated": true})
+        );
+
+        let root = ops.schema_graph_with_depth(instance_id, 0).graph;
+        assert_eq!(root["schema_id"]["truncated"], true);
+        assert_eq!(ops.schema_graph_with_depth(instance_id, 0).nodes().len(), 1);
+
+        let full = ops.schema_graph(instance_id);
+        assert_eq!(
+            full.graph,
+            ops.schema_graph_with_depth(instance_id, 2).graph
+        );
+        assert_eq!(full.nodes().len(), 3);
+    }
+
+    #[test]
+    fn test_gts_ops_attr_on_schema() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let base = json!({
+            "$id": "gts://gts.x.test.attr.event.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "description": "Base event",
+            "type": "object",
+            "properties": {"name": {"type": "string"}}
+        });
+        let derived = json!({
+            "$id": "gts://gts.x.test.attr.event.v1~x.test.attr.created.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "allOf": [
+                {"$ref": "gts://gts.x.test.attr.event.v1~"},
+                {"type": "object", "properties": {"size": {"type": "integer"}}}
+            ]
+        });
+        assert!(ops.add_entity(&base, false).ok);
+        assert!(ops.add_entity(&derived, false).ok);
+
+        let result = ops.attr("gts.x.test.attr.event.v1~#properties.name.type");
+        assert!(result.resolved, "{:?}", result.error);
+        assert_eq!(result.value, Some(json!("string")));
+
+        // Inherited properties resolve on the derived schema, with either separator
+        let result =
+            ops.attr("gts.x.test.attr.event.v1~x.test.attr.created.v1~#properties.name.type");
+        assert_eq!(result.value, Some(json!("string")));
+        let result =
+            ops.attr("gts.x.test.attr.event.v1~x.test.attr.created.v1~@properties.size.type");
+        assert_eq!(result.value, Some(json!("integer")));
+
+        let result = ops.attr("gts.x.test.attr.event.v1~#properties.missing");
+        assert!(!result.resolved);
+
+        // Paths outside `properties` resolve against the schema as written
+        let result = ops.attr("gts.x.test.attr.event.v1~@description");
+        assert_eq!(result.value, Some(json!("Base event")));
+        let result = ops.attr("gts.x.test.attr.event.v1~@type");
+        assert_eq!(result.value, Some(json!("object")));
+        let result = ops.attr("gts.x.test.attr.event.v1~x.test.attr.created.v1~@$id");
+        assert_eq!(
+            result.value,
+            Some(json!(
+                "gts://gts.x.test.attr.event.v1~x.test.attr.created.v1~"
+            ))
+        );
+    }
+
     #[test]
     fn test_gts_ops_attr_no_path() {
         let mut ops = GtsOps::new(None, None, 0);
@@ -3074,7 +4133,7 @@ mod tests {
             json!(["$schema", "$id", "schemaId"]),
         );
 
-        let config = GtsOps::create_config_from_data(&data);
+        let config = GtsConfig::from_data(&data);
         assert_eq!(config.entity_id_fields, vec!["customId", "uuid", "id"]);
         assert_eq!(config.schema_id_fields, vec!["$schema", "$id", "schemaId"]);
     }
@@ -3082,7 +4141,7 @@ mod tests {
     #[test]
     fn test_create_config_from_data_with_empty_data() {
         let data = HashMap::new();
-        let config = GtsOps::create_config_from_data(&data);
+        let config = GtsConfig::from_data(&data);
 
         // Should use default config values
         let default_cfg = GtsConfig::default();
@@ -3097,7 +4156,7 @@ mod tests {
         data.insert("entity_id_fields".to_owned(), json!("not-an-array"));
         data.insert("schema_id_fields".to_owned(), json!(123));
 
-        let config = GtsOps::create_config_from_data(&data);
+        let config = GtsConfig::from_data(&data);
 
         // Should fall back to default values
         let default_cfg = GtsConfig::default();
@@ -3133,6 +4192,7 @@ mod tests {
             "Error should mention schema validation failure, got: {}",
             result.error
         );
+        assert_eq!(result.id, "gts.test.invalid.schema.broken.v1~");
     }
 
     #[test]
@@ -3374,4 +4434,451 @@ mod tests {
         assert_eq!(result.id, "gts.test.get.entity.success.v1~");
         assert!(result.is_schema);
     }
+
+    fn add_versioned_schemas(ops: &mut GtsOps, ids: &[&str]) {
+        for id in ids {
+            let schema = json!({
+                "$id": format!("gts://{id}"),
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object"
+            });
+            assert!(ops.add_entity(&schema, false).ok);
+        }
+    }
+
+    #[test]
+    fn test_plan_migration_upgrade_through_intermediate_versions() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(
+            &mut ops,
+            &[
+                "gts.x.test.migrate.order.v1.2~",
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.order.v2.0~",
+                "gts.x.test.migrate.order.v1.1~",
+                "gts.x.test.migrate.other.v1.5~",
+            ],
+        );
+
+        let plan = ops
+            .plan_migration(
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.order.v2.0~",
+            )
+            .expect("test");
+        assert_eq!(
+            plan.steps,
+            vec![
+                (
+                    "gts.x.test.migrate.order.v1.0~".to_owned(),
+                    "gts.x.test.migrate.order.v1.1~".to_owned()
+                ),
+                (
+                    "gts.x.test.migrate.order.v1.1~".to_owned(),
+                    "gts.x.test.migrate.order.v1.2~".to_owned()
+                ),
+                (
+                    "gts.x.test.migrate.order.v1.2~".to_owned(),
+                    "gts.x.test.migrate.order.v2.0~".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_migration_downgrade_and_same_version() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(
+            &mut ops,
+            &[
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.order.v1.1~",
+            ],
+        );
+
+        let plan = ops
+            .plan_migration(
+                "gts.x.test.migrate.order.v1.1~",
+                "gts.x.test.migrate.order.v1.0~",
+            )
+            .expect("test");
+        assert_eq!(
+            plan.steps,
+            vec![(
+                "gts.x.test.migrate.order.v1.1~".to_owned(),
+                "gts.x.test.migrate.order.v1.0~".to_owned()
+            )]
+        );
+
+        let plan = ops
+            .plan_migration(
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.order.v1.0~",
+            )
+            .expect("test");
+        assert!(plan.steps.is_empty());
+    }
+
+    #[test]
+    fn test_plan_migration_errors() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(
+            &mut ops,
+            &[
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.other.v1.1~",
+            ],
+        );
+
+        assert!(matches!(
+            ops.plan_migration("gts.x.test.migrate.order.v1.0~", "gts.x.test.migrate.order.v3.0~"),
+            Err(StoreError::SchemaNotFound(id)) if id == "gts.x.test.migrate.order.v3.0~"
+        ));
+        assert!(matches!(
+            ops.plan_migration(
+                "gts.x.test.migrate.order.v1.0~",
+                "gts.x.test.migrate.other.v1.1~"
+            ),
+            Err(StoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            ops.plan_migration("gts.x.test.migrate.order.v1.0~", "not-a-schema"),
+            Err(StoreError::InvalidSchemaId)
+        ));
+    }
+
+    #[test]
+    fn test_check_referential_integrity() {
+        let mut ops = GtsOps::new(None, None, 0);
+        for schema in [
+            json!({
+                "$id": "gts://gts.x.test.integrity.user.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object"
+            }),
+            json!({
+                "$id": "gts://gts.x.test.integrity.order.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "properties": {
+                    "id": {"type": "string"},
+                    "owner": {"type": "string", "x-gts-ref": "gts.x.test.integrity.user.v1~"}
+                }
+            }),
+        ] {
+            assert!(ops.add_entity(&schema, false).ok);
+        }
+        for instance in [
+            json!({"id": "gts.x.test.integrity.user.v1~x.test.integrity.alice.v1"}),
+            json!({
+                "id": "gts.x.test.integrity.order.v1~x.test.integrity.first.v1",
+                "owner": "gts.x.test.integrity.user.v1~x.test.integrity.alice.v1"
+            }),
+            json!({
+                "id": "gts.x.test.integrity.order.v1~x.test.integrity.second.v1",
+                "owner": "gts.x.test.integrity.user.v1~x.test.integrity.bob.v1"
+            }),
+            json!({
+                "id": "gts.x.test.integrity.order.v1~x.test.integrity.third.v1",
+                "owner": "gts.x.test.integrity.order.v1~x.test.integrity.first.v1"
+            }),
+        ] {
+            assert!(ops.add_entity(&instance, false).ok);
+        }
+
+        let errors = ops.check_referential_integrity();
+        assert_eq!(
+            errors,
+            vec![
+                IntegrityError {
+                    instance_id: "gts.x.test.integrity.order.v1~x.test.integrity.second.v1"
+                        .to_owned(),
+                    field_path: "owner".to_owned(),
+                    unresolved_ref_id: "gts.x.test.integrity.user.v1~x.test.integrity.bob.v1"
+                        .to_owned(),
+                },
+                IntegrityError {
+                    instance_id: "gts.x.test.integrity.order.v1~x.test.integrity.third.v1"
+                        .to_owned(),
+                    field_path: "owner".to_owned(),
+                    unresolved_ref_id: "gts.x.test.integrity.order.v1~x.test.integrity.first.v1"
+                        .to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schema_stats() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let base = json!({
+            "$id": "gts://gts.x.test.stats.event.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+                "id": {"type": "string"},
+                "payload": {"type": "object"}
+            }
+        });
+        let derived = json!({
+            "$id": "gts://gts.x.test.stats.event.v1~x.test.stats.created.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "description": "Entity created event",
+            "allOf": [
+                {"$ref": "gts://gts.x.test.stats.event.v1~"},
+                {
+                    "type": "object",
+                    "required": ["name"],
+                    "additionalProperties": false,
+                    "properties": {
+                        "name": {"type": "string"},
+                        "size": {"type": ["integer", "null"]},
+                        "extra": {}
+                    }
+                }
+            ]
+        });
+        assert!(ops.add_entity(&base, false).ok);
+        assert!(ops.add_entity(&derived, false).ok);
+
+        let stats = ops.schema_stats("gts.x.test.stats.event.v1~");
+        assert!(stats.error.is_empty());
+        assert_eq!(stats.total_properties, 2);
+        assert_eq!(stats.required_properties, 1);
+        assert_eq!(stats.optional_properties, 1);
+        assert!(stats.has_additional_properties);
+        assert_eq!(stats.description, None);
+        assert_eq!(stats.depth, 1);
+
+        let stats = ops.schema_stats("gts.x.test.stats.event.v1~x.test.stats.created.v1~");
+        assert!(stats.error.is_empty());
+        assert_eq!(stats.total_properties, 5);
+        assert_eq!(stats.required_properties, 2);
+        assert_eq!(stats.optional_properties, 3);
+        assert_eq!(stats.property_types["id"], "string");
+        assert_eq!(stats.property_types["size"], "integer|null");
+        assert_eq!(stats.property_types["extra"], "any");
+        assert!(!stats.has_additional_properties);
+        assert_eq!(stats.description.as_deref(), Some("Entity created event"));
+        assert_eq!(stats.depth, 2);
+
+        let missing = ops.schema_stats("gts.x.test.stats.missing.v1~");
+        assert!(!missing.error.is_empty());
+        assert_eq!(missing.total_properties, 0);
+    }
+
+    #[test]
+    fn test_version_history() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(
+            &mut ops,
+            &[
+                "gts.x.test.history.event.v2~",
+                "gts.x.test.history.event.v1.1~",
+                "gts.x.test.history.event.v1~",
+                "gts.x.test.history.event.v1.0~",
+                "gts.x.test.history.other.v1~",
+                "gts.x.test.history.event.v1~x.test.history.child.v1~",
+            ],
+        );
+        let expected = vec![
+            "gts.x.test.history.event.v1~".to_owned(),
+            "gts.x.test.history.event.v1.0~".to_owned(),
+            "gts.x.test.history.event.v1.1~".to_owned(),
+            "gts.x.test.history.event.v2~".to_owned(),
+        ];
+
+        assert_eq!(
+            ops.version_history("gts.x.test.history.event.v1.0~"),
+            expected
+        );
+        assert_eq!(
+            ops.version_history("gts.x.test.history.event.v9~"),
+            expected
+        );
+        assert_eq!(ops.version_history("gts.x.test.history.event.*~"), expected);
+        assert_eq!(ops.version_history("gts.x.test.history.event.*"), expected);
+        assert_eq!(
+            ops.version_history("gts.x.test.history.event.v1~x.test.history.child.*~"),
+            vec!["gts.x.test.history.event.v1~x.test.history.child.v1~".to_owned()]
+        );
+        assert!(
+            ops.version_history("gts.x.test.history.missing.v1~")
+                .is_empty()
+        );
+        assert!(ops.version_history("not-a-gts-id").is_empty());
+    }
+
+    #[test]
+    fn test_unused_schemas() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(
+            &mut ops,
+            &["gts.x.test.unused.user.v1~", "gts.x.test.unused.order.v1~"],
+        );
+        assert_eq!(
+            ops.unused_schemas(),
+            vec![
+                "gts.x.test.unused.order.v1~".to_owned(),
+                "gts.x.test.unused.user.v1~".to_owned(),
+            ]
+        );
+
+        let instance = json!({"id": "gts.x.test.unused.user.v1~x.test.unused.alice.v1"});
+        assert!(ops.add_entity(&instance, false).ok);
+        assert_eq!(
+            ops.store.count_instances_of("gts.x.test.unused.user.v1~"),
+            1
+        );
+        assert_eq!(
+            ops.unused_schemas(),
+            vec!["gts.x.test.unused.order.v1~".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_normalize_id() {
+        assert_eq!(
+            GtsOps::normalize_id("  gts://GTS.X.Test.Norm.User.V1~  ").expect("test"),
+            "gts.x.test.norm.user.v1~"
+        );
+        assert_eq!(
+            GtsOps::normalize_id("gts.x.test.norm.user.v1~x.test.norm.alice.v1").expect("test"),
+            "gts.x.test.norm.user.v1~x.test.norm.alice.v1"
+        );
+        assert!(GtsOps::normalize_id("gts://not a gts id").is_err());
+        assert!(GtsOps::normalize_id("").is_err());
+    }
+
+    #[test]
+    fn test_add_entity_and_validate_instance_normalize_ids() {
+        let mut ops = GtsOps::new(None, None, 0);
+        let schema = json!({
+            "$id": " GTS://gts.x.test.norm.user.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {"id": {"type": "string"}}
+        });
+        let result = ops.add_entity(&schema, false);
+        assert!(result.ok, "{}", result.error);
+        assert_eq!(result.id, "gts.x.test.norm.user.v1~");
+
+        let instance = json!({"id": "  GTS.x.test.norm.user.v1~X.test.norm.alice.v1 "});
+        let result = ops.add_entity(&instance, false);
+        assert!(result.ok, "{}", result.error);
+        assert_eq!(result.id, "gts.x.test.norm.user.v1~x.test.norm.alice.v1");
+        assert_eq!(
+            result.schema_id.as_deref(),
+            Some("gts.x.test.norm.user.v1~")
+        );
+
+        let validation =
+            ops.validate_instance("gts://GTS.X.TEST.NORM.USER.V1~X.TEST.NORM.ALICE.V1");
+        assert!(validation.ok, "{}", validation.error);
+    }
+
+    #[test]
+    fn test_new_with_config() {
+        let cfg = GtsConfig::builder().entity_id_field("key").build();
+        let mut ops = GtsOps::new_with_config(None, cfg, 0);
+        assert_eq!(ops.cfg.entity_id_fields, vec!["key".to_owned()]);
+
+        let schema = json!({
+            "key": "gts.x.test.cfg.item.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        });
+        let result = ops.add_entity(&schema, false);
+        assert!(result.ok, "{}", result.error);
+        assert_eq!(result.id, "gts.x.test.cfg.item.v1~");
+    }
+
+    #[test]
+    fn test_export_and_import_file() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(&mut ops, &["gts.x.test.export.user.v1~"]);
+        for instance in [
+            json!({"id": "gts.x.test.export.user.v1~x.test.export.alice.v1"}),
+            json!({
+                "id": "7a1d2f34-5678-49ab-8def-0123456789ab",
+                "type": "gts.x.test.export.user.v1~"
+            }),
+        ] {
+            assert!(ops.add_entity(&instance, false).ok);
+        }
+
+        let path = std::env::temp_dir().join(format!("gts_export_{}.json", std::process::id()));
+        ops.export_to_file(&path).expect("test");
+
+        let mut restored = GtsOps::new(None, None, 0);
+        assert_eq!(restored.import_from_file(&path).expect("test"), 3);
+        std::fs::remove_file(&path).expect("test");
+
+        let mut ids: Vec<&String> = restored.store.items().map(|(id, _)| id).collect();
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                "7a1d2f34-5678-49ab-8def-0123456789ab",
+                "gts.x.test.export.user.v1~",
+                "gts.x.test.export.user.v1~x.test.export.alice.v1",
+            ]
+        );
+        let anonymous = restored
+            .store
+            .get("7a1d2f34-5678-49ab-8def-0123456789ab")
+            .expect("test");
+        assert_eq!(
+            anonymous.schema_id.as_deref(),
+            Some("gts.x.test.export.user.v1~")
+        );
+        assert!(
+            restored
+                .store
+                .get("gts.x.test.export.user.v1~")
+                .expect("test")
+                .is_schema
+        );
+
+        let missing = std::env::temp_dir().join("gts_export_missing_file.json");
+        assert!(matches!(
+            restored.import_from_file(&missing),
+            Err(StoreError::ImportError(_))
+        ));
+    }
+
+    #[test]
+    fn test_search() {
+        let mut ops = GtsOps::new(None, None, 0);
+        add_versioned_schemas(&mut ops, &["gts.x.test.search.user.v1~"]);
+        for instance in [
+            json!({
+                "id": "gts.x.test.search.user.v1~x.test.search.alice.v1",
+                "name": "Alice Smith",
+                "address": {"city": "Berlin"}
+            }),
+            json!({
+                "id": "gts.x.test.search.user.v1~x.test.search.bob.v1",
+                "name": "Bob",
+                "tags": ["smithing", "berlin"]
+            }),
+        ] {
+            assert!(ops.add_entity(&instance, false).ok);
+        }
+
+        let result = ops.search("SMITH", &[], 10);
+        assert_eq!(result.count, 2);
+        assert_eq!(result.results[0]["name"], "Alice Smith");
+
+        let result = ops.search("berlin", &["city"], 10);
+        assert_eq!(result.count, 1);
+        assert_eq!(result.results[0]["name"], "Alice Smith");
+
+        let result = ops.search("smith", &["name"], 10);
+        assert_eq!(result.count, 1);
+
+        assert_eq!(ops.search("smith", &[], 1).count, 1);
+        assert_eq!(ops.search("nobody", &[], 10).count, 0);
+    }
 }
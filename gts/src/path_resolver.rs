@@ -35,7 +35,7 @@ impl JsonPathResolver {
     fn split_raw_parts(norm: &str) -> Vec<String> {
         norm.split('.')
             .filter(|s| !s.is_empty())
-            .map(ToString::to_string)
+            .map(str::to_owned)
             .collect()
     }
 
@@ -198,6 +198,26 @@ impl JsonPathResolver {
         self
     }
 
+    /// Looks up `path` in `content` with the same syntax as [`JsonPathResolver::resolve`],
+    /// borrowing the value instead of building a resolver.
+    pub(crate) fn lookup<'a>(content: &'a Value, path: &str) -> Option<&'a Value> {
+        let mut cur = content;
+        for p in Self::parts(path) {
+            cur = match cur {
+                Value::Array(arr) => {
+                    let idx = p
+                        .strip_prefix('[')
+                        .and_then(|p| p.strip_suffix(']'))
+                        .unwrap_or(&p);
+                    arr.get(idx.parse::<usize>().ok()?)?
+                }
+                Value::Object(map) if !p.starts_with('[') => map.get(&p)?,
+                _ => return None,
+            };
+        }
+        Some(cur)
+    }
+
     #[must_use]
     pub fn failure(mut self, path: &str, error: &str) -> Self {
         path.clone_into(&mut self.path);
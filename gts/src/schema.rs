@@ -4,6 +4,12 @@
 //! composition for nested generic types like `BaseEventV1<AuditPayloadV1<PlaceOrderDataV1>>`.
 
 use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use uuid::Uuid;
+
+use crate::gts::{GTS_PREFIX, GTS_URI_PREFIX};
+use crate::store::{GtsStore, StoreError};
 
 /// Trait for types that have a GTS schema.
 ///
@@ -29,6 +35,25 @@ pub trait GtsSchema {
     /// For example, `BaseEventV1<P>` has `payload` as the generic field.
     const GENERIC_FIELD: Option<&'static str> = None;
 
+    /// The struct fields included in the schema, as listed in the macro's `properties`,
+    /// under their serialized (renamed) property names.
+    const SCHEMA_PROPERTIES: &'static [&'static str] = &[];
+
+    /// The major version from the last segment of `SCHEMA_ID` (e.g., `1` for `...v1.2~`).
+    const SCHEMA_VERSION_MAJOR: u32 = 0;
+
+    /// The minor version from the last segment of `SCHEMA_ID`, if present (e.g., `Some(2)` for `...v1.2~`).
+    const SCHEMA_VERSION_MINOR: Option<u32> = None;
+
+    /// The schema ID of the direct parent type, or `None` for a base type.
+    ///
+    /// For `gts.x.core.events.type.v1~x.core.audit.event.v1~` this is
+    /// `Some("gts.x.core.events.type.v1~")`.
+    #[must_use]
+    fn parent_schema_id() -> Option<&'static str> {
+        None
+    }
+
     /// Returns the JSON schema for this type with $ref references intact.
     fn gts_schema_with_refs() -> Value;
 
@@ -58,6 +83,71 @@ pub trait GtsSchema {
         Self::gts_schema_with_refs()
     }
 
+    /// Validate a JSON value against this type's schema.
+    ///
+    /// The schema from [`GtsSchema::gts_schema_with_refs`] is compiled on first use and the
+    /// validator is cached per type. Schemas that `$ref` a parent via `gts://` cannot be
+    /// resolved here; validate those through [`crate::GtsStore::validate_instance`].
+    ///
+    /// # Errors
+    /// Returns the list of validation error messages, or a single message if the
+    /// schema fails to compile.
+    fn validate_value(value: &Value) -> Result<(), Vec<String>> {
+        type ValidatorCache = Mutex<HashMap<&'static str, Arc<jsonschema::Validator>>>;
+        static VALIDATORS: OnceLock<ValidatorCache> = OnceLock::new();
+
+        let key = std::any::type_name::<Self>();
+        let cache = VALIDATORS.get_or_init(ValidatorCache::default);
+        let cached = cache
+            .lock()
+            .ok()
+            .and_then(|validators| validators.get(key).cloned());
+
+        let validator = if let Some(validator) = cached {
+            validator
+        } else {
+            let compiled = jsonschema::validator_for(&Self::gts_schema_with_refs())
+                .map_err(|e| vec![format!("Invalid schema '{}': {e}", Self::SCHEMA_ID)])?;
+            let compiled = Arc::new(compiled);
+            if let Ok(mut validators) = cache.lock() {
+                validators.insert(key, Arc::clone(&compiled));
+            }
+            compiled
+        };
+
+        let errors: Vec<String> = validator
+            .iter_errors(value)
+            .map(|err| err.to_string())
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Register this type's schema in the given store under [`GtsSchema::SCHEMA_ID`].
+    ///
+    /// # Errors
+    /// Returns `StoreError::InvalidSchemaId` if `SCHEMA_ID` is not a valid schema ID.
+    fn register_in_store(store: &mut GtsStore) -> Result<(), StoreError> {
+        store.register_schema(Self::SCHEMA_ID, &Self::gts_schema_with_refs())
+    }
+
+    /// Build the ID of an anonymous instance of this type identified by `uuid`.
+    ///
+    /// The UUID (hyphens replaced by underscores) becomes the type token of a segment
+    /// under the schema's vendor, e.g. `gts.x.test.entities.person.v1~x.anonymous.instance.u<uuid>.v1`.
+    #[must_use]
+    fn make_gts_id_from_uuid(uuid: Uuid) -> String {
+        let vendor = Self::SCHEMA_ID
+            .strip_prefix(GTS_PREFIX)
+            .and_then(|rest| rest.split('.').next())
+            .unwrap_or("x");
+        let token = uuid.hyphenated().to_string().replace('-', "_");
+        format!("{}{vendor}.anonymous.instance.u{token}.v1", Self::SCHEMA_ID)
+    }
+
     /// Get the innermost schema ID in a nested generic chain.
     /// For `BaseEventV1<AuditPayloadV1<PlaceOrderDataV1>>`, returns `PlaceOrderDataV1`'s ID.
     #[must_use]
@@ -79,18 +169,19 @@ pub trait GtsSchema {
         Vec::new()
     }
 
-    /// Wrap properties in a nested structure following the nesting path.
-    /// For path `["payload", "data"]` and properties `{order_id, product_id, last}`,
-    /// returns `{ "payload": { "type": "object", "properties": { "data": { "type": "object", "additionalProperties": false, "properties": {...}, "required": [...] } } } }`
+    /// The generic field names leading from this type to the innermost type with
+    /// properties, for inspecting how a nested generic type is composed.
     ///
-    /// The `additionalProperties: false` is placed on the object that contains the current type's
-    /// own properties. Generic fields that will be extended by children are just `{"type": "object"}`.
+    /// For `BaseEventV1<AuditPayloadV1<PlaceOrderDataV1>>`, returns `["payload", "data"]`;
+    /// for a non-generic type or `BaseEventV1<()>`, returns an empty path.
+    #[must_use]
+    fn nesting_path() -> Vec<&'static str> {
+        Self::collect_nesting_path()
+    }
+
+    /// Wrap properties in a nested structure following the nesting path.
     ///
-    /// # Arguments
-    /// * `path` - The nesting path from outer to inner (e.g., `["payload", "data"]`)
-    /// * `properties` - The properties of the current type
-    /// * `required` - The required fields of the current type
-    /// * `generic_field` - The name of the generic field in the current type (if any), which should NOT have additionalProperties: false
+    /// See [`wrap_in_nesting_path`].
     #[must_use]
     fn wrap_in_nesting_path(
         path: &[&str],
@@ -98,47 +189,7 @@ pub trait GtsSchema {
         required: Value,
         generic_field: Option<&str>,
     ) -> Value {
-        if path.is_empty() {
-            return properties;
-        }
-
-        // Build the innermost schema - this contains the current type's own properties
-        // Set additionalProperties: false on this level (the object containing our properties)
-        let mut current = serde_json::json!({
-            "type": "object",
-            "additionalProperties": false,
-            "properties": properties,
-            "required": required
-        });
-
-        // If we have a generic field, ensure it's just {"type": "object"} without additionalProperties
-        // This field will be extended by child schemas
-        if let Some(gf) = generic_field
-            && let Some(props) = current
-                .get_mut("properties")
-                .and_then(|v| v.as_object_mut())
-            && props.contains_key(gf)
-        {
-            props.insert(gf.to_owned(), serde_json::json!({"type": "object"}));
-        }
-
-        // Wrap from inner to outer - parent levels don't need additionalProperties: false
-        for field in path.iter().rev() {
-            current = serde_json::json!({
-                "type": "object",
-                "properties": {
-                    *field: current
-                }
-            });
-        }
-
-        // Extract just the properties object from the outermost wrapper
-        // since the caller will put this in a "properties" field
-        if let Some(props) = current.get("properties") {
-            return props.clone();
-        }
-
-        current
+        wrap_in_nesting_path(path, properties, required, generic_field)
     }
 }
 
@@ -187,6 +238,68 @@ macro_rules! gts_schema_for {
     }};
 }
 
+/// Wrap properties in a nested structure following the nesting path.
+/// For path `["payload", "data"]` and properties `{order_id, product_id, last}`,
+/// returns `{ "payload": { "type": "object", "properties": { "data": { "type": "object", "additionalProperties": false, "properties": {...}, "required": [...] } } } }`
+///
+/// The `additionalProperties: false` is placed on the object that contains the current type's
+/// own properties. Generic fields that will be extended by children are just `{"type": "object"}`.
+///
+/// # Arguments
+/// * `path` - The nesting path from outer to inner (e.g., `["payload", "data"]`)
+/// * `properties` - The properties of the current type
+/// * `required` - The required fields of the current type
+/// * `generic_field` - The name of the generic field in the current type (if any), which should NOT have additionalProperties: false
+#[must_use]
+pub fn wrap_in_nesting_path(
+    path: &[&str],
+    properties: Value,
+    required: Value,
+    generic_field: Option<&str>,
+) -> Value {
+    if path.is_empty() {
+        return properties;
+    }
+
+    // Build the innermost schema - this contains the current type's own properties
+    // Set additionalProperties: false on this level (the object containing our properties)
+    let mut current = serde_json::json!({
+        "type": "object",
+        "additionalProperties": false,
+    });
+    current["properties"] = properties;
+    current["required"] = required;
+
+    // If we have a generic field, ensure it's just {"type": "object"} without additionalProperties
+    // This field will be extended by child schemas
+    if let Some(gf) = generic_field
+        && let Some(props) = current
+            .get_mut("properties")
+            .and_then(|v| v.as_object_mut())
+        && props.contains_key(gf)
+    {
+        props.insert(gf.to_owned(), serde_json::json!({"type": "object"}));
+    }
+
+    // Wrap from inner to outer - parent levels don't need additionalProperties: false
+    for field in path.iter().rev() {
+        current = serde_json::json!({
+            "type": "object",
+            "properties": {
+                *field: current
+            }
+        });
+    }
+
+    // Extract just the properties object from the outermost wrapper
+    // since the caller will put this in a "properties" field
+    if let Some(props) = current.get("properties") {
+        return props.clone();
+    }
+
+    current
+}
+
 /// Strip schema metadata fields ($id, $schema, title, description) for cleaner nested schemas.
 #[must_use]
 pub fn strip_schema_metadata(schema: &Value) -> Value {
@@ -228,12 +341,12 @@ pub fn build_gts_allof_schema(
     required: &[&str],
 ) -> Value {
     serde_json::json!({
-        "$id": format!("gts://{}", innermost_schema_id),
+        "$id": format!("{GTS_URI_PREFIX}{innermost_schema_id}"),
         "$schema": "http://json-schema.org/draft-07/schema#",
         "title": title,
         "type": "object",
         "allOf": [
-            { "$ref": format!("gts://{}", base_schema_id) },
+            { "$ref": format!("{GTS_URI_PREFIX}{base_schema_id}") },
             {
                 "type": "object",
                 "properties": own_properties,
@@ -256,6 +369,9 @@ mod tests {
         assert_eq!(schema, json!({"type": "object"}));
         assert_eq!(<()>::SCHEMA_ID, "");
         assert_eq!(<()>::GENERIC_FIELD, None);
+        assert!(<()>::SCHEMA_PROPERTIES.is_empty());
+        assert_eq!(<()>::SCHEMA_VERSION_MAJOR, 0);
+        assert_eq!(<()>::SCHEMA_VERSION_MINOR, None);
     }
 
     #[test]
@@ -338,6 +454,28 @@ mod tests {
         assert_eq!(props.get("field1").unwrap(), &json!({"type": "string"}));
     }
 
+    #[test]
+    fn test_wrap_in_nesting_path_free_function_matches_trait_method() {
+        let properties = json!({"order_id": {"type": "string"}, "data": {"type": "string"}});
+        let required = json!(["order_id"]);
+
+        let result = wrap_in_nesting_path(
+            &["payload", "data"],
+            properties.clone(),
+            required.clone(),
+            Some("data"),
+        );
+
+        assert_eq!(
+            result,
+            <()>::wrap_in_nesting_path(&["payload", "data"], properties, required, Some("data"))
+        );
+        assert_eq!(
+            result["payload"]["properties"]["data"]["properties"]["data"],
+            json!({"type": "object"})
+        );
+    }
+
     #[test]
     fn test_strip_schema_metadata_removes_all_metadata() {
         // Test removal of all metadata fields including $id, $schema, title, description
@@ -473,4 +611,73 @@ mod tests {
         assert_eq!(props_obj.get("properties").unwrap(), &properties);
         assert_eq!(props_obj.get("required").unwrap(), &json!(required));
     }
+
+    struct TestPersonV1;
+
+    impl GtsSchema for TestPersonV1 {
+        const SCHEMA_ID: &'static str = "gts.x.test.entities.person.v1~";
+
+        fn gts_schema_with_refs() -> Value {
+            json!({
+                "$id": "gts://gts.x.test.entities.person.v1~",
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string"},
+                    "age": {"type": "integer", "minimum": 0}
+                },
+                "required": ["name"]
+            })
+        }
+    }
+
+    #[test]
+    fn test_validate_value_accepts_valid_instance() {
+        assert!(TestPersonV1::validate_value(&json!({"name": "Ann", "age": 30})).is_ok());
+        // Second call goes through the cached validator
+        assert!(TestPersonV1::validate_value(&json!({"name": "Bob"})).is_ok());
+    }
+
+    #[test]
+    fn test_validate_value_collects_all_errors() {
+        let errors = TestPersonV1::validate_value(&json!({"age": -1})).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("name")));
+    }
+
+    #[test]
+    fn test_validate_value_unit_type() {
+        assert!(<()>::validate_value(&json!({})).is_ok());
+        assert!(<()>::validate_value(&json!("not an object")).is_err());
+    }
+
+    #[test]
+    fn test_make_gts_id_from_uuid() {
+        let uuid = Uuid::parse_str("550E8400-E29B-41D4-A716-446655440000").unwrap();
+        let id = TestPersonV1::make_gts_id_from_uuid(uuid);
+        assert_eq!(
+            id,
+            "gts.x.test.entities.person.v1~x.anonymous.instance.u550e8400_e29b_41d4_a716_446655440000.v1"
+        );
+        assert!(crate::GtsID::is_valid(&id));
+    }
+
+    #[test]
+    fn test_register_in_store() {
+        let mut store = GtsStore::new(None);
+        TestPersonV1::register_in_store(&mut store).unwrap();
+
+        let content = store
+            .get_schema_content("gts.x.test.entities.person.v1~")
+            .unwrap();
+        assert_eq!(content, TestPersonV1::gts_schema_with_refs());
+    }
+
+    #[test]
+    fn test_register_in_store_rejects_unit_type() {
+        let mut store = GtsStore::new(None);
+        assert!(matches!(
+            <()>::register_in_store(&mut store),
+            Err(StoreError::InvalidSchemaId)
+        ));
+    }
 }
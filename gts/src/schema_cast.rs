@@ -19,6 +19,22 @@ pub enum SchemaCastError {
     CastError(String),
 }
 
+/// JSON (de)serialization failures during a cast are internal errors.
+impl From<serde_json::Error> for SchemaCastError {
+    fn from(e: serde_json::Error) -> Self {
+        SchemaCastError::InternalError(e.to_string())
+    }
+}
+
+/// Output language of [`GtsEntityCastResult::to_migration_script`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationLanguage {
+    /// `ALTER TABLE` statements for a table named after the target type.
+    Sql,
+    /// A Rust function applying the changes to a `serde_json::Value`.
+    Rust,
+}
+
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GtsEntityCastResult {
@@ -39,6 +55,9 @@ pub struct GtsEntityCastResult {
     pub backward_errors: Vec<String>,
     pub forward_errors: Vec<String>,
     pub casted_entity: Option<Value>,
+    /// ID the casted entity was registered under (set by `GtsOps::cast_and_register`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub casted_entity_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
@@ -96,6 +115,7 @@ impl GtsEntityCastResult {
                         backward_errors,
                         forward_errors,
                         casted_entity: None,
+                        casted_entity_id: None,
                         error: None,
                     });
                 }
@@ -131,6 +151,7 @@ impl GtsEntityCastResult {
             backward_errors,
             forward_errors,
             casted_entity: Some(Value::Object(casted)),
+            casted_entity_id: None,
             error: None,
         })
     }
@@ -155,7 +176,152 @@ impl GtsEntityCastResult {
         "unknown".to_owned()
     }
 
-    fn effective_object_schema(s: &Value) -> Value {
+    /// Describes the result in a few lines, for CLI output and log messages, e.g.
+    /// `Upgrading gts.x.type.v1.0~ → gts.x.type.v1.1~: BACKWARD COMPATIBLE`
+    /// followed by the added, removed and changed properties and any incompatibilities.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let verb = match self.direction.as_str() {
+            "up" => "Upgrading",
+            "down" => "Downgrading",
+            _ => "Casting",
+        };
+        let header = format!("{verb} {} \u{2192} {}", self.from_id, self.to_id);
+        if let Some(error) = &self.error {
+            return format!("{header}: FAILED: {error}");
+        }
+
+        let compatibility = match (self.is_backward_compatible, self.is_forward_compatible) {
+            (true, true) => "FULLY COMPATIBLE",
+            (true, false) => "BACKWARD COMPATIBLE",
+            (false, true) => "FORWARD COMPATIBLE",
+            (false, false) => "INCOMPATIBLE",
+        };
+        let describe = |verb: &str, properties: &[String]| match properties.len() {
+            0 => format!("No properties {}.", verb.to_lowercase()),
+            1 => format!("{verb} 1 property: [{}].", properties[0]),
+            n => format!("{verb} {n} properties: [{}].", properties.join(", ")),
+        };
+
+        let mut lines = vec![
+            format!("{header}: {compatibility}."),
+            describe("Added", &self.added_properties),
+            describe("Removed", &self.removed_properties),
+        ];
+        if !self.changed_properties.is_empty() {
+            lines.push(format!(
+                "Changed {} properties.",
+                self.changed_properties.len()
+            ));
+        }
+        lines.extend(
+            self.incompatibility_reasons
+                .iter()
+                .chain(&self.backward_errors)
+                .chain(&self.forward_errors)
+                .map(|reason| format!("- {reason}")),
+        );
+        lines.join("\n")
+    }
+
+    /// Generates a migration snippet for the added, removed and changed properties.
+    ///
+    /// Added properties default to their value in `casted_entity` when there is one.
+    /// The output is a starting point to review and adapt, not production code.
+    #[must_use]
+    pub fn to_migration_script(&self, language: MigrationLanguage) -> String {
+        let default_of = |path: &str| {
+            self.casted_entity
+                .as_ref()
+                .and_then(|entity| entity.pointer(&MigrationPath::new(path).pointer()))
+        };
+        let changed: Vec<String> = self
+            .changed_properties
+            .iter()
+            .map(|change| {
+                let mut entries: Vec<String> =
+                    change.iter().map(|(k, v)| format!("{k}={v}")).collect();
+                entries.sort();
+                entries.join(", ")
+            })
+            .collect();
+
+        let mut lines = Vec::new();
+        match language {
+            MigrationLanguage::Sql => {
+                let table = GtsID::new(&self.to_id)
+                    .ok()
+                    .and_then(|id| id.gts_id_segments.last().map(|s| s.type_name.clone()))
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or_else(|| "entities".to_owned());
+                let quote = |name: &str| format!("\"{}\"", name.replace('"', "\"\""));
+                lines.push(format!(
+                    "-- Migration from {} to {}",
+                    self.from_id, self.to_id
+                ));
+                for path in &self.added_properties {
+                    let column_type = match default_of(path) {
+                        Some(Value::String(_)) => "TEXT",
+                        Some(Value::Number(n)) if n.is_f64() => "DOUBLE PRECISION",
+                        Some(Value::Number(_)) => "BIGINT",
+                        Some(Value::Bool(_)) => "BOOLEAN",
+                        _ => "JSONB",
+                    };
+                    lines.push(format!(
+                        "ALTER TABLE {} ADD COLUMN {} {column_type};",
+                        quote(&table),
+                        quote(path)
+                    ));
+                }
+                for path in &self.removed_properties {
+                    lines.push(format!(
+                        "ALTER TABLE {} DROP COLUMN {};",
+                        quote(&table),
+                        quote(path)
+                    ));
+                }
+                for change in &changed {
+                    lines.push(format!("-- TODO: review changed property: {change}"));
+                }
+            }
+            MigrationLanguage::Rust => {
+                lines.push(format!(
+                    "/// Migrates an instance from `{}` to `{}`.",
+                    self.from_id, self.to_id
+                ));
+                lines.push("pub fn migrate(value: &mut serde_json::Value) {".to_owned());
+                let mut step = |path: &MigrationPath, action: String| {
+                    lines.push(format!(
+                        "    if let Some(obj) = value.pointer_mut({:?}).and_then(serde_json::Value::as_object_mut) {{",
+                        path.parent
+                    ));
+                    lines.push(format!("        {action};"));
+                    lines.push("    }".to_owned());
+                };
+                for path in &self.added_properties {
+                    let default = default_of(path).unwrap_or(&Value::Null);
+                    let path = MigrationPath::new(path);
+                    let action = format!(
+                        "obj.entry({:?}).or_insert(serde_json::json!({default}))",
+                        path.key
+                    );
+                    step(&path, action);
+                }
+                for path in &self.removed_properties {
+                    let path = MigrationPath::new(path);
+                    let action = format!("obj.remove({:?})", path.key);
+                    step(&path, action);
+                }
+                for change in &changed {
+                    lines.push(format!("    // TODO: review changed property: {change}"));
+                }
+                lines.push("}".to_owned());
+            }
+        }
+        lines.join("\n")
+    }
+
+    pub(crate) fn effective_object_schema(s: &Value) -> Value {
         if let Some(obj) = s.as_object() {
             if obj.contains_key("properties") || obj.contains_key("required") {
                 return s.clone();
@@ -205,7 +371,7 @@ impl GtsEntityCastResult {
             .and_then(|r| r.as_array())
             .map(|arr| {
                 arr.iter()
-                    .filter_map(|v| v.as_str().map(ToString::to_string))
+                    .filter_map(|v| v.as_str().map(str::to_owned))
                     .collect()
             })
             .unwrap_or_default();
@@ -561,7 +727,7 @@ impl GtsEntityCastResult {
             .and_then(|r| r.as_array())
             .map(|arr| {
                 arr.iter()
-                    .filter_map(|v| v.as_str().map(ToString::to_string))
+                    .filter_map(|v| v.as_str().map(str::to_owned))
                     .collect()
             })
             .unwrap_or_default();
@@ -571,7 +737,7 @@ impl GtsEntityCastResult {
             .and_then(|r| r.as_array())
             .map(|arr| {
                 arr.iter()
-                    .filter_map(|v| v.as_str().map(ToString::to_string))
+                    .filter_map(|v| v.as_str().map(str::to_owned))
                     .collect()
             })
             .unwrap_or_default();
@@ -619,11 +785,11 @@ impl GtsEntityCastResult {
                 if let (Some(old_e), Some(new_e)) = (old_enum, new_enum) {
                     let old_enum_set: HashSet<String> = old_e
                         .iter()
-                        .filter_map(|v| v.as_str().map(ToString::to_string))
+                        .filter_map(|v| v.as_str().map(str::to_owned))
                         .collect();
                     let new_enum_set: HashSet<String> = new_e
                         .iter()
-                        .filter_map(|v| v.as_str().map(ToString::to_string))
+                        .filter_map(|v| v.as_str().map(str::to_owned))
                         .collect();
 
                     if check_backward {
@@ -680,6 +846,36 @@ impl GtsEntityCastResult {
         (errors.is_empty(), errors)
     }
 }
+/// A property path from a cast result (e.g. `address.lines[0].text`) split into the
+/// JSON pointer of its parent object and its own key.
+struct MigrationPath {
+    parent: String,
+    key: String,
+}
+
+impl MigrationPath {
+    fn new(path: &str) -> Self {
+        let mut tokens: Vec<String> = path
+            .replace('[', ".")
+            .replace(']', "")
+            .split('.')
+            .filter(|t| !t.is_empty())
+            .map(str::to_owned)
+            .collect();
+        let key = tokens.pop().unwrap_or_default();
+        let parent = tokens.iter().map(|t| Self::escape(t)).collect();
+        MigrationPath { parent, key }
+    }
+
+    fn pointer(&self) -> String {
+        format!("{}{}", self.parent, Self::escape(&self.key))
+    }
+
+    fn escape(token: &str) -> String {
+        format!("/{}", token.replace('~', "~0").replace('/', "~1"))
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -722,6 +918,22 @@ mod tests {
         assert!(error.to_string().contains("cast error"));
     }
 
+    #[test]
+    fn test_schema_cast_error_from_serde_json() {
+        fn parse(input: &str) -> Result<Value, SchemaCastError> {
+            Ok(serde_json::from_str(input)?)
+        }
+
+        let error = parse("{not json").unwrap_err();
+        assert!(matches!(error, SchemaCastError::InternalError(_)));
+        assert!(
+            error
+                .to_string()
+                .starts_with("Internal error: key must be a string")
+        );
+        assert!(parse("{}").is_ok());
+    }
+
     #[test]
     fn test_json_entity_cast_result_infer_direction_up() {
         let direction = GtsEntityCastResult::infer_direction(
@@ -768,6 +980,7 @@ mod tests {
             backward_errors: vec![],
             forward_errors: vec![],
             casted_entity: None,
+            casted_entity_id: None,
             error: None,
         };
 
@@ -787,6 +1000,93 @@ mod tests {
         );
     }
 
+    fn sample_result() -> GtsEntityCastResult {
+        GtsEntityCastResult {
+            from_id: "gts.x.test.cast.user.v1.0~".to_owned(),
+            to_id: "gts.x.test.cast.user.v1.1~".to_owned(),
+            old: "gts.x.test.cast.user.v1.0~".to_owned(),
+            new: "gts.x.test.cast.user.v1.1~".to_owned(),
+            direction: "up".to_owned(),
+            added_properties: vec!["email".to_owned(), "phone".to_owned()],
+            removed_properties: vec![],
+            changed_properties: vec![],
+            is_fully_compatible: false,
+            is_backward_compatible: true,
+            is_forward_compatible: false,
+            incompatibility_reasons: vec![],
+            backward_errors: vec![],
+            forward_errors: vec!["Added required property 'email'".to_owned()],
+            casted_entity: None,
+            casted_entity_id: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_cast_result_summary() {
+        let result = sample_result();
+        assert_eq!(
+            result.summary(),
+            "Upgrading gts.x.test.cast.user.v1.0~ \u{2192} gts.x.test.cast.user.v1.1~: BACKWARD COMPATIBLE.\n\
+             Added 2 properties: [email, phone].\n\
+             No properties removed.\n\
+             - Added required property 'email'"
+        );
+
+        let mut result = sample_result();
+        result.direction = "down".to_owned();
+        result.is_backward_compatible = false;
+        result.added_properties.clear();
+        result.removed_properties = vec!["age".to_owned()];
+        let summary = result.summary();
+        assert!(summary.starts_with("Downgrading "));
+        assert!(summary.contains(": INCOMPATIBLE."));
+        assert!(summary.contains("No properties added."));
+        assert!(summary.contains("Removed 1 property: [age]."));
+
+        let mut result = sample_result();
+        result.error = Some("Entity not found".to_owned());
+        assert!(result.summary().ends_with(": FAILED: Entity not found"));
+    }
+
+    #[test]
+    fn test_cast_result_to_migration_script() {
+        let mut result = sample_result();
+        result.removed_properties = vec!["legacy.code".to_owned()];
+        result.casted_entity = Some(json!({"email": "", "phone": 5}));
+
+        assert_eq!(
+            result.to_migration_script(MigrationLanguage::Sql),
+            "-- Migration from gts.x.test.cast.user.v1.0~ to gts.x.test.cast.user.v1.1~\n\
+             ALTER TABLE \"user\" ADD COLUMN \"email\" TEXT;\n\
+             ALTER TABLE \"user\" ADD COLUMN \"phone\" BIGINT;\n\
+             ALTER TABLE \"user\" DROP COLUMN \"legacy.code\";"
+        );
+
+        let rust = result.to_migration_script(MigrationLanguage::Rust);
+        assert!(rust.starts_with("/// Migrates an instance from `gts.x.test.cast.user.v1.0~`"));
+        assert!(rust.contains("pub fn migrate(value: &mut serde_json::Value) {"));
+        assert!(rust.contains(
+            "    if let Some(obj) = value.pointer_mut(\"\").and_then(serde_json::Value::as_object_mut) {\n\
+             \x20       obj.entry(\"phone\").or_insert(serde_json::json!(5));"
+        ));
+        assert!(rust.contains("value.pointer_mut(\"/legacy\")"));
+        assert!(rust.contains("obj.remove(\"code\");"));
+        assert!(rust.ends_with("\n}"));
+    }
+
+    #[test]
+    fn test_migration_path() {
+        let path = MigrationPath::new("address.lines[0].text");
+        assert_eq!(path.parent, "/address/lines/0");
+        assert_eq!(path.key, "text");
+        assert_eq!(path.pointer(), "/address/lines/0/text");
+
+        let path = MigrationPath::new("email");
+        assert_eq!(path.parent, "");
+        assert_eq!(path.pointer(), "/email");
+    }
+
     #[test]
     fn test_check_schema_compatibility_identical() {
         let schema1 = json!({
@@ -1,11 +1,14 @@
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 use thiserror::Error;
 
-use crate::entities::GtsEntity;
-use crate::gts::{GTS_URI_PREFIX, GtsID, GtsWildcard};
+use crate::entities::{GtsEntity, MANUAL_SCHEMA_ID_FIELD};
+use crate::gts::{GTS_PREFIX, GTS_URI_PREFIX, GtsID, GtsWildcard, strip_gts_uri};
 use crate::schema_cast::GtsEntityCastResult;
 
 /// Custom retriever for resolving gts:// URI scheme references in JSON Schema validation
@@ -20,9 +23,8 @@ impl GtsRetriever {
         // Pre-populate with all schemas from the store
         for (id, entity) in store_map {
             if entity.is_schema {
-                // Store with gts:// URI format
-                let uri = format!("{GTS_URI_PREFIX}{id}");
-                schemas.insert(uri, entity.content.clone());
+                // Store under the canonical ID; `retrieve` strips the gts:// prefix
+                schemas.insert(id.clone(), entity.content.clone());
             }
         }
 
@@ -43,22 +45,22 @@ impl jsonschema::Retrieve for GtsRetriever {
         tracing::debug!("GtsRetriever: Attempting to retrieve URI: {uri_str}");
 
         // Only handle gts:// URIs
-        if !uri_str.starts_with(GTS_URI_PREFIX) {
+        let Some(gts_id) = strip_gts_uri(uri_str) else {
             tracing::warn!("GtsRetriever: Unknown scheme for URI: {uri_str}");
             return Err(format!("Unknown scheme for URI: {uri_str}").into());
-        }
+        };
 
         let store = self.store.read().map_err(|e| format!("Lock error: {e}"))?;
 
         tracing::debug!("GtsRetriever: Store contains {} schemas", store.len());
 
-        if let Some(schema) = store.get(uri_str) {
+        if let Some(schema) = store.get(gts_id) {
             tracing::debug!("GtsRetriever: Successfully retrieved schema for {uri_str}");
             Ok(schema.clone())
         } else {
             tracing::warn!("GtsRetriever: Schema not found: {uri_str}");
             tracing::debug!(
-                "GtsRetriever: Available URIs: {:?}",
+                "GtsRetriever: Available IDs: {:?}",
                 store.keys().collect::<Vec<_>>()
             );
             Err(format!("Schema not found: {uri_str}").into())
@@ -88,6 +90,23 @@ pub enum StoreError {
     ValidationError(String),
     #[error("Invalid $ref: {0}")]
     InvalidRef(String),
+    #[error("Entity with GTS ID '{0}' is already registered")]
+    DuplicateEntity(String),
+    #[error("Failed to import store: {0}")]
+    ImportError(String),
+    /// The IDs forming a reference cycle, in order (without repeating the first ID).
+    #[error("Circular reference: {}", format_cycle(.0))]
+    CircularReference(Vec<String>),
+}
+
+/// Formats a cycle as `A → B → C → A`.
+fn format_cycle(ids: &[String]) -> String {
+    let mut path = ids.join(" \u{2192} ");
+    if let Some(first) = ids.first() {
+        path.push_str(" \u{2192} ");
+        path.push_str(first);
+    }
+    path
 }
 
 pub trait GtsReader: Send {
@@ -105,16 +124,77 @@ pub struct GtsStoreQueryResult {
     pub results: Vec<Value>,
 }
 
+/// Pagination and ordering for [`GtsStore::query_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryOptions {
+    pub limit: usize,
+    /// Number of matching entities to skip before collecting results.
+    pub offset: usize,
+    /// Top-level content field to sort by; results are sorted by ID when unset.
+    pub sort_by: Option<String>,
+    pub sort_desc: bool,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        QueryOptions {
+            limit: 100,
+            offset: 0,
+            sort_by: None,
+            sort_desc: false,
+        }
+    }
+}
+
+impl GtsStoreQueryResult {
+    /// Deserializes each result into `T` without cloning the underlying JSON.
+    ///
+    /// Each item yields its own `Result`, so one malformed entity does not stop iteration.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_typed_iter<T: DeserializeOwned>(
+        &self,
+    ) -> impl Iterator<Item = Result<T, serde_json::Error>> + use<'_, T> {
+        self.results.iter().map(T::deserialize)
+    }
+}
+
+/// Summary of the entities dropped by [`GtsStore::compact`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompactReport {
+    /// IDs of every removed entity, sorted.
+    pub removed_ids: Vec<String>,
+    pub instances_removed: usize,
+    pub schemas_removed: usize,
+}
+
+/// Receives change notifications from a [`GtsStore`].
+///
+/// Listeners are called synchronously from `register`, `register_schema` and `remove`.
+pub trait StoreListener: Send + Sync {
+    fn on_register(&self, entity: &GtsEntity);
+    fn on_remove(&self, id: &str);
+}
+
 pub struct GtsStore {
     by_id: HashMap<String, GtsEntity>,
-    reader: Option<Box<dyn GtsReader>>,
+    /// Behind a mutex so the store is `Sync` without requiring `Sync` readers. Only
+    /// `&mut self` methods use the reader, so it is reached through `Mutex::get_mut`.
+    reader: Option<Mutex<Box<dyn GtsReader>>>,
+    listeners: Vec<Arc<dyn StoreListener>>,
+    /// Top-level content fields covered by `by_field`.
+    indexed_fields: Vec<String>,
+    /// Secondary index from `(field, value)` to the IDs of entities with that value.
+    by_field: HashMap<(String, String), Vec<String>>,
 }
 
 impl GtsStore {
     pub fn new(reader: Option<Box<dyn GtsReader>>) -> Self {
         let mut store = GtsStore {
             by_id: HashMap::new(),
-            reader,
+            reader: reader.map(Mutex::new),
+            listeners: Vec::new(),
+            indexed_fields: Vec::new(),
+            by_field: HashMap::new(),
         };
 
         if store.reader.is_some() {
@@ -125,8 +205,90 @@ impl GtsStore {
         store
     }
 
+    /// Adds a listener that is notified when entities are registered or removed.
+    #[must_use]
+    pub fn with_listener(mut self, listener: Arc<dyn StoreListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Maintains a secondary index on the given top-level content fields.
+    ///
+    /// Queries with an exact-value filter on an indexed field only scan the
+    /// matching entities instead of the whole store.
+    #[must_use]
+    pub fn with_field_index(mut self, fields: &[&str]) -> Self {
+        for field in fields {
+            if !self.indexed_fields.iter().any(|f| f == field) {
+                self.indexed_fields.push((*field).to_owned());
+            }
+        }
+        self.rebuild_indexes();
+        self
+    }
+
+    /// Reconstructs all secondary indexes from the entities currently in the store.
+    pub fn rebuild_indexes(&mut self) {
+        self.by_field.clear();
+        let by_id = std::mem::take(&mut self.by_id);
+        for (id, entity) in &by_id {
+            self.index_entity(id, entity);
+        }
+        self.by_id = by_id;
+    }
+
+    /// Formats a field value the way query filters compare it.
+    fn index_value(value: &Value) -> String {
+        value
+            .as_str()
+            .map_or_else(|| value.to_string(), ToOwned::to_owned)
+    }
+
+    fn index_keys(&self, entity: &GtsEntity) -> Vec<(String, String)> {
+        let Some(obj) = entity.content.as_object() else {
+            return Vec::new();
+        };
+        self.indexed_fields
+            .iter()
+            .filter_map(|field| {
+                obj.get(field)
+                    .map(|value| (field.clone(), Self::index_value(value)))
+            })
+            .collect()
+    }
+
+    fn index_entity(&mut self, id: &str, entity: &GtsEntity) {
+        for key in self.index_keys(entity) {
+            let ids = self.by_field.entry(key).or_default();
+            if !ids.iter().any(|existing| existing == id) {
+                ids.push(id.to_owned());
+            }
+        }
+    }
+
+    fn unindex_entity(&mut self, id: &str, entity: &GtsEntity) {
+        for key in self.index_keys(entity) {
+            if let Some(ids) = self.by_field.get_mut(&key) {
+                ids.retain(|existing| existing != id);
+                if ids.is_empty() {
+                    self.by_field.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Inserts an entity into `by_id`, keeping the field index in sync.
+    fn insert_entity(&mut self, id: String, entity: GtsEntity) {
+        if let Some(previous) = self.by_id.remove(&id) {
+            self.unindex_entity(&id, &previous);
+        }
+        self.index_entity(&id, &entity);
+        self.by_id.insert(id, entity);
+    }
+
     fn populate_from_reader(&mut self) {
-        if let Some(ref mut reader) = self.reader {
+        if let Some(reader) = &mut self.reader {
+            let reader = reader.get_mut().unwrap_or_else(PoisonError::into_inner);
             for entity in reader.iter() {
                 // Use effective_id() which handles both GTS IDs and anonymous instance IDs
                 if let Some(id) = entity.effective_id() {
@@ -142,10 +304,28 @@ impl GtsStore {
     /// Returns `StoreError::InvalidEntity` if the entity has no effective ID.
     pub fn register(&mut self, entity: GtsEntity) -> Result<(), StoreError> {
         let id = entity.effective_id().ok_or(StoreError::InvalidEntity)?;
-        self.by_id.insert(id, entity);
+        let _span = tracing::info_span!("gts.store.register", operation = "register", gts_id = %id)
+            .entered();
+        for listener in &self.listeners {
+            listener.on_register(&entity);
+        }
+        self.insert_entity(id, entity);
         Ok(())
     }
 
+    /// Registers an entity in the store, refusing to overwrite an existing entry.
+    ///
+    /// # Errors
+    /// Returns `StoreError::InvalidEntity` if the entity has no effective ID, or
+    /// `StoreError::DuplicateEntity` if an entity with the same ID is already registered.
+    pub fn register_strict(&mut self, entity: GtsEntity) -> Result<(), StoreError> {
+        let id = entity.effective_id().ok_or(StoreError::InvalidEntity)?;
+        if self.by_id.contains_key(&id) {
+            return Err(StoreError::DuplicateEntity(id));
+        }
+        self.register(entity)
+    }
+
     /// Registers a schema in the store.
     ///
     /// # Errors
@@ -167,10 +347,99 @@ impl GtsStore {
             None,
             None,
         );
-        self.by_id.insert(type_id.to_owned(), entity);
+        for listener in &self.listeners {
+            listener.on_register(&entity);
+        }
+        self.insert_entity(type_id.to_owned(), entity);
         Ok(())
     }
 
+    /// Removes an entity from the store, returning it if it was present.
+    pub fn remove(&mut self, entity_id: &str) -> Option<GtsEntity> {
+        let removed = self.by_id.remove(entity_id)?;
+        self.unindex_entity(entity_id, &removed);
+        for listener in &self.listeners {
+            listener.on_remove(entity_id);
+        }
+        Some(removed)
+    }
+
+    /// Returns the schema an instance conforms to.
+    ///
+    /// Uses the instance's `schema_id`, falling back to the type prefix of a
+    /// chained instance ID (`type~instance` resolves to `type~`).
+    ///
+    /// # Errors
+    /// Returns `StoreError::ObjectNotFound` if the instance is not in the store,
+    /// `StoreError::SchemaForInstanceNotFound` if no schema ID can be determined, and
+    /// `StoreError::SchemaNotFound` if the schema is not registered.
+    pub fn schema_for(&mut self, instance_id: &str) -> Result<GtsEntity, StoreError> {
+        let instance = self
+            .get(instance_id)
+            .ok_or_else(|| StoreError::ObjectNotFound(instance_id.to_owned()))?;
+
+        let schema_id = instance
+            .schema_id
+            .clone()
+            .filter(|id| id.starts_with(GTS_PREFIX))
+            .or_else(|| {
+                let gts_id = instance.gts_id.as_ref()?;
+                let end = gts_id.id.rfind('~')?;
+                (end + 1 < gts_id.id.len()).then(|| gts_id.id[..=end].to_owned())
+            })
+            .ok_or_else(|| StoreError::SchemaForInstanceNotFound(instance_id.to_owned()))?;
+
+        match self.get(&schema_id) {
+            Some(schema) if schema.is_schema => Ok(schema.clone()),
+            _ => Err(StoreError::SchemaNotFound(schema_id)),
+        }
+    }
+
+    /// Removes orphaned entities from the store.
+    ///
+    /// An instance is orphaned when its `schema_id` names a schema that is not
+    /// registered; a schema is unreachable when its parent GTS schema is not
+    /// registered. Removal repeats until no orphans remain, since dropping a
+    /// schema can orphan its instances and derived schemas. Secondary indexes are
+    /// rebuilt afterwards.
+    pub fn compact(&mut self) -> CompactReport {
+        let mut report = CompactReport::default();
+
+        loop {
+            let orphans: Vec<(String, bool)> = self
+                .by_id
+                .iter()
+                .filter(|(_, entity)| {
+                    entity.schema_id.as_ref().is_some_and(|schema_id| {
+                        let is_gts_ref = !entity.is_schema || schema_id.starts_with(GTS_PREFIX);
+                        is_gts_ref && !self.by_id.get(schema_id).is_some_and(|s| s.is_schema)
+                    })
+                })
+                .map(|(id, entity)| (id.clone(), entity.is_schema))
+                .collect();
+
+            if orphans.is_empty() {
+                break;
+            }
+
+            for (id, is_schema) in orphans {
+                if self.remove(&id).is_some() {
+                    if is_schema {
+                        report.schemas_removed += 1;
+                    } else {
+                        report.instances_removed += 1;
+                    }
+                    report.removed_ids.push(id);
+                }
+            }
+        }
+
+        self.by_id.shrink_to_fit();
+        self.rebuild_indexes();
+        report.removed_ids.sort();
+        report
+    }
+
     pub fn get(&mut self, entity_id: &str) -> Option<&GtsEntity> {
         // Check cache first
         if self.by_id.contains_key(entity_id) {
@@ -178,16 +447,41 @@ impl GtsStore {
         }
 
         // Try to fetch from reader
-        if let Some(ref reader) = self.reader
-            && let Some(entity) = reader.read_by_id(entity_id)
+        if let Some(reader) = &mut self.reader
+            && let Some(entity) = reader
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .read_by_id(entity_id)
         {
-            self.by_id.insert(entity_id.to_owned(), entity);
+            self.insert_entity(entity_id.to_owned(), entity);
             return self.by_id.get(entity_id);
         }
 
         None
     }
 
+    /// Re-reads an entity from the reader, replacing the cached copy.
+    ///
+    /// Falls back to the cached entity when there is no reader or the reader does not
+    /// know the ID (e.g. the entity was registered directly).
+    pub fn refresh(&mut self, entity_id: &str) -> Option<&GtsEntity> {
+        if let Some(reader) = &mut self.reader
+            && let Some(entity) = reader
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .read_by_id(entity_id)
+        {
+            self.insert_entity(entity_id.to_owned(), entity);
+        }
+        self.by_id.get(entity_id)
+    }
+
+    /// Looks up an already-loaded entity without falling back to the reader.
+    #[must_use]
+    pub fn get_cached(&self, entity_id: &str) -> Option<&GtsEntity> {
+        self.by_id.get(entity_id)
+    }
+
     /// Gets the content of a schema by its type ID.
     ///
     /// # Errors
@@ -203,6 +497,109 @@ impl GtsStore {
         self.by_id.iter()
     }
 
+    /// Returns one page of entries, ordered by entity ID so pages are stable across calls.
+    #[must_use]
+    pub fn items_paginated(&self, offset: usize, limit: usize) -> Vec<(&String, &GtsEntity)> {
+        let mut items: Vec<_> = self.by_id.iter().collect();
+        items.sort_unstable_by_key(|(id, _)| *id);
+        items.into_iter().skip(offset).take(limit).collect()
+    }
+
+    /// Counts the registered instances whose schema is exactly `schema_id`.
+    #[must_use]
+    pub fn count_instances_of(&self, schema_id: &str) -> usize {
+        self.by_id
+            .values()
+            .filter(|e| !e.is_schema && e.schema_id.as_deref() == Some(schema_id))
+            .count()
+    }
+
+    /// Returns the registered schema `schema_id` with every `$ref` inlined, following
+    /// multi-level chains. `$id` and `$schema` are removed so that the result can be
+    /// compiled directly with the `jsonschema` crate.
+    ///
+    /// # Errors
+    /// Returns `StoreError::SchemaNotFound` if `schema_id` is not a registered schema, or
+    /// `StoreError::CircularReference` if its `$ref` chain loops back on itself.
+    pub fn resolve_all_refs(&self, schema_id: &str) -> Result<Value, StoreError> {
+        let entity = self
+            .by_id
+            .get(schema_id)
+            .filter(|entity| entity.is_schema)
+            .ok_or_else(|| StoreError::SchemaNotFound(schema_id.to_owned()))?;
+
+        if let Some(cycle) = self.find_ref_cycle(
+            schema_id,
+            &mut Vec::new(),
+            &mut std::collections::HashSet::new(),
+        ) {
+            return Err(StoreError::CircularReference(cycle));
+        }
+
+        let mut resolved = self.resolve_schema_refs(&entity.content);
+        if let Value::Object(ref mut map) = resolved {
+            map.remove("$id");
+            map.remove("$schema");
+        }
+        Ok(resolved)
+    }
+
+    /// Follows the `gts://` `$ref`s of registered schemas depth-first from `schema_id`,
+    /// returning the IDs forming a cycle if one is reached. `done` holds schemas whose
+    /// references are already known to be acyclic.
+    fn find_ref_cycle(
+        &self,
+        schema_id: &str,
+        path: &mut Vec<String>,
+        done: &mut std::collections::HashSet<String>,
+    ) -> Option<Vec<String>> {
+        if let Some(pos) = path.iter().position(|id| id == schema_id) {
+            return Some(path[pos..].to_vec());
+        }
+        if done.contains(schema_id) {
+            return None;
+        }
+        let entity = self
+            .by_id
+            .get(schema_id)
+            .filter(|entity| entity.is_schema)?;
+
+        let mut refs = Vec::new();
+        Self::collect_gts_schema_refs(&entity.content, &mut refs);
+        path.push(schema_id.to_owned());
+        for ref_id in refs {
+            if let Some(cycle) = self.find_ref_cycle(&ref_id, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(schema_id.to_owned());
+        None
+    }
+
+    /// Collects the canonical IDs of the `gts://` `$ref`s found anywhere in `value`.
+    fn collect_gts_schema_refs(value: &Value, refs: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    if key == "$ref"
+                        && let Some(ref_id) = child.as_str().and_then(strip_gts_uri)
+                    {
+                        refs.push(ref_id.to_owned());
+                    } else {
+                        Self::collect_gts_schema_refs(child, refs);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    Self::collect_gts_schema_refs(item, refs);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Resolve all `$ref` references in a JSON Schema by inlining the referenced schemas.
     ///
     /// This method recursively traverses the schema, finds all `$ref` references,
@@ -258,7 +655,7 @@ impl GtsStore {
                     }
 
                     // Normalize the ref: strip gts:// prefix to get canonical GTS ID
-                    let canonical_ref = ref_uri.strip_prefix(GTS_URI_PREFIX).unwrap_or(ref_uri);
+                    let canonical_ref = strip_gts_uri(ref_uri).unwrap_or(ref_uri);
 
                     // Try to resolve the reference using canonical ID
                     if let Some(entity) = self.by_id.get(canonical_ref)
@@ -473,7 +870,7 @@ impl GtsStore {
                         // Valid local ref
                     }
                     // GTS refs must use gts:// URI format
-                    else if let Some(gts_id) = ref_uri.strip_prefix(GTS_URI_PREFIX) {
+                    else if let Some(gts_id) = strip_gts_uri(ref_uri) {
                         // Validate the GTS ID
                         if !GtsID::is_valid(gts_id) {
                             return Err(StoreError::InvalidRef(format!(
@@ -518,7 +915,15 @@ impl GtsStore {
     ///
     /// # Errors
     /// Returns `StoreError` if validation fails.
+    #[allow(clippy::cognitive_complexity)]
     pub fn validate_schema(&mut self, gts_id: &str) -> Result<(), StoreError> {
+        let _span = tracing::info_span!(
+            "gts.store.validate_schema",
+            operation = "validate_schema",
+            gts_id = %gts_id
+        )
+        .entered();
+
         if !gts_id.ends_with('~') {
             return Err(StoreError::SchemaNotFound(format!(
                 "ID '{gts_id}' is not a schema (must end with '~')"
@@ -558,7 +963,7 @@ impl GtsStore {
         let mut schema_for_validation = Self::remove_x_gts_ref_fields(&schema_content);
 
         // Check if schema contains gts:// references
-        let has_gts_refs = schema_for_validation.to_string().contains("gts://");
+        let has_gts_refs = schema_for_validation.to_string().contains(GTS_URI_PREFIX);
 
         if has_gts_refs {
             // Skip jsonschema compilation for schemas with gts:// references during registration
@@ -591,25 +996,106 @@ impl GtsStore {
         Ok(())
     }
 
+    /// Validates a schema and every ancestor in its inheritance chain.
+    ///
+    /// The ancestors of `a~b~c~` are `a~b~` and `a~`; they are validated after the
+    /// schema itself, nearest first.
+    ///
+    /// # Errors
+    /// Returns the first error encountered. A missing ancestor is reported as
+    /// `StoreError::SchemaNotFound` with its ID; other ancestor failures are reported as
+    /// `StoreError::ValidationError` naming the ancestor.
+    pub fn validate_schema_chain(&mut self, schema_id: &str) -> Result<(), StoreError> {
+        self.validate_schema(schema_id)?;
+
+        let Ok(gid) = GtsID::new(schema_id) else {
+            return Ok(());
+        };
+        for depth in (1..gid.gts_id_segments.len()).rev() {
+            let segments: String = gid.gts_id_segments[..depth]
+                .iter()
+                .map(|s| s.segment.as_str())
+                .collect();
+            let ancestor_id = format!("{GTS_PREFIX}{segments}");
+            match self.validate_schema(&ancestor_id) {
+                Ok(()) => {}
+                Err(e @ StoreError::SchemaNotFound(_)) => return Err(e),
+                Err(e) => {
+                    return Err(StoreError::ValidationError(format!(
+                        "Ancestor schema '{ancestor_id}' of '{schema_id}' is invalid: {e}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Validates an instance against its schema.
     ///
     /// # Errors
     /// Returns `StoreError` if validation fails.
     pub fn validate_instance(&mut self, gts_id: &str) -> Result<(), StoreError> {
+        let _span = tracing::info_span!(
+            "gts.store.validate_instance",
+            operation = "validate_instance",
+            gts_id = %gts_id
+        )
+        .entered();
+
+        self.load_for_validation(gts_id);
+        self.validate_loaded_instance(gts_id)
+    }
+
+    /// Validates several instances, running the validations in parallel.
+    ///
+    /// Instances and their schemas are first loaded from the reader one by one; the
+    /// validations then only read the store. Results are in the order of `gts_ids`.
+    pub fn validate_instances(&mut self, gts_ids: &[String]) -> Vec<Result<(), StoreError>> {
+        let _span = tracing::info_span!(
+            "gts.store.validate_instances",
+            operation = "validate_instances",
+            count = gts_ids.len()
+        )
+        .entered();
+
+        for gts_id in gts_ids {
+            self.load_for_validation(gts_id);
+        }
+        let store = &*self;
+        gts_ids
+            .par_iter()
+            .map(|gts_id| store.validate_loaded_instance(gts_id))
+            .collect()
+    }
+
+    /// Fetches an instance and its schema from the reader if they are not loaded yet.
+    fn load_for_validation(&mut self, gts_id: &str) {
+        if let Ok(gid) = GtsID::new(gts_id)
+            && let Some(schema_id) = self.get(&gid.id).and_then(|obj| obj.schema_id.clone())
+        {
+            self.get(&schema_id);
+        }
+    }
+
+    /// Validates an instance using only the entities already loaded in the store.
+    fn validate_loaded_instance(&self, gts_id: &str) -> Result<(), StoreError> {
         let gid = GtsID::new(gts_id).map_err(|_| StoreError::ObjectNotFound(gts_id.to_owned()))?;
 
         let obj = self
+            .by_id
             .get(&gid.id)
-            .ok_or_else(|| StoreError::ObjectNotFound(gts_id.to_owned()))?
-            .clone();
+            .ok_or_else(|| StoreError::ObjectNotFound(gts_id.to_owned()))?;
 
         let schema_id = obj
             .schema_id
             .as_ref()
-            .ok_or_else(|| StoreError::SchemaForInstanceNotFound(gid.id.clone()))?
-            .clone();
+            .ok_or_else(|| StoreError::SchemaForInstanceNotFound(gid.id.clone()))?;
 
-        let schema = self.get_schema_content(&schema_id)?;
+        let schema = &self
+            .by_id
+            .get(schema_id)
+            .ok_or_else(|| StoreError::SchemaNotFound(schema_id.clone()))?
+            .content;
 
         tracing::info!(
             "Validating instance {} against schema {}",
@@ -619,7 +1105,7 @@ impl GtsStore {
 
         // Resolve internal #/ references (like #/$defs/GtsInstanceId) by inlining them
         // This handles the compile-time inlining of GtsInstanceId and GtsSchemaId
-        let schema_with_internal_refs_resolved = self.resolve_schema_refs(&schema);
+        let schema_with_internal_refs_resolved = self.resolve_schema_refs(schema);
 
         tracing::debug!(
             "Schema for validation: {}",
@@ -655,7 +1141,7 @@ impl GtsStore {
 
         // Validate x-gts-ref constraints
         let validator = crate::x_gts_ref::XGtsRefValidator::new();
-        let x_gts_ref_errors = validator.validate_instance(&obj.content, &schema, "");
+        let x_gts_ref_errors = validator.validate_instance(&obj.content, schema, "");
 
         if !x_gts_ref_errors.is_empty() {
             let error_messages: Vec<String> = x_gts_ref_errors
@@ -685,6 +1171,14 @@ impl GtsStore {
         from_id: &str,
         target_schema_id: &str,
     ) -> Result<GtsEntityCastResult, StoreError> {
+        let _span = tracing::info_span!(
+            "gts.store.cast",
+            operation = "cast",
+            gts_id = %from_id,
+            target_schema_id = %target_schema_id
+        )
+        .entered();
+
         let from_entity = self
             .get(from_id)
             .ok_or_else(|| StoreError::EntityNotFound(from_id.to_owned()))?
@@ -729,6 +1223,112 @@ impl GtsStore {
             .map_err(|e| StoreError::SchemaNotFound(e.to_string()))
     }
 
+    /// Casts every instance of `from_schema` to `to_schema` and registers the results.
+    ///
+    /// Instances with a chained GTS ID are registered under the same ID with the
+    /// `from_schema` prefix replaced by `to_schema`; anonymous instances keep their
+    /// ID and are updated in place. Returns the new ID (or error) per instance,
+    /// in ID order.
+    pub fn cast_all_instances(
+        &mut self,
+        from_schema: &str,
+        to_schema: &str,
+    ) -> Vec<Result<String, StoreError>> {
+        let mut ids: Vec<String> = self
+            .by_id
+            .iter()
+            .filter(|(_, entity)| {
+                !entity.is_schema && entity.schema_id.as_deref() == Some(from_schema)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.sort();
+
+        ids.into_iter()
+            .map(|id| self.cast_and_register(&id, from_schema, to_schema))
+            .collect()
+    }
+
+    fn cast_and_register(
+        &mut self,
+        from_id: &str,
+        from_schema: &str,
+        to_schema: &str,
+    ) -> Result<String, StoreError> {
+        let result = self.cast(from_id, to_schema)?;
+        let Some(content) = result.casted_entity else {
+            return Err(StoreError::ValidationError(
+                result
+                    .error
+                    .unwrap_or_else(|| result.incompatibility_reasons.join("; ")),
+            ));
+        };
+        self.register_casted(from_id, from_schema, to_schema, content)
+    }
+
+    /// Registers `content`, the result of casting `from_id` to `to_schema`, and returns
+    /// its ID.
+    ///
+    /// A chained GTS ID gets its `from_schema` prefix replaced by `to_schema`; an
+    /// anonymous instance keeps its ID and is replaced in place.
+    ///
+    /// # Errors
+    /// Returns `StoreError::EntityNotFound` if `from_id` is not loaded, or
+    /// `StoreError::ValidationError` if the new ID is not a valid GTS ID.
+    pub fn register_casted(
+        &mut self,
+        from_id: &str,
+        from_schema: &str,
+        to_schema: &str,
+        mut content: Value,
+    ) -> Result<String, StoreError> {
+        let source = self
+            .by_id
+            .get(from_id)
+            .cloned()
+            .ok_or_else(|| StoreError::EntityNotFound(from_id.to_owned()))?;
+
+        let new_id = from_id.strip_prefix(from_schema).map_or_else(
+            || from_id.to_owned(),
+            |suffix| format!("{to_schema}{suffix}"),
+        );
+
+        if let Some(obj) = content.as_object_mut() {
+            if let Some(field) = &source.selected_entity_field {
+                obj.insert(field.clone(), Value::String(new_id.clone()));
+            }
+            if let Some(field) = &source.selected_schema_id_field
+                && source.selected_entity_field.as_ref() != Some(field)
+                && field != MANUAL_SCHEMA_ID_FIELD
+            {
+                obj.insert(field.clone(), Value::String(to_schema.to_owned()));
+            }
+        }
+
+        let gts_id = if source.gts_id.is_some() {
+            Some(GtsID::new(&new_id).map_err(|e| StoreError::ValidationError(e.to_string()))?)
+        } else {
+            None
+        };
+        let mut entity = GtsEntity::new(
+            None,
+            None,
+            &content,
+            None,
+            gts_id,
+            false,
+            new_id.clone(),
+            None,
+            Some(to_schema.to_owned()),
+        );
+        entity.instance_id = Some(new_id.clone());
+        entity.selected_entity_field = source.selected_entity_field;
+        entity.selected_schema_id_field = source.selected_schema_id_field;
+
+        self.register(entity)?;
+        Ok(new_id)
+    }
+
     pub fn is_minor_compatible(
         &mut self,
         old_schema_id: &str,
@@ -754,6 +1354,7 @@ impl GtsStore {
                 backward_errors: vec!["Schema not found".to_owned()],
                 forward_errors: vec!["Schema not found".to_owned()],
                 casted_entity: None,
+                casted_entity_id: None,
                 error: None,
             };
         };
@@ -786,22 +1387,49 @@ impl GtsStore {
             backward_errors,
             forward_errors,
             casted_entity: None,
+            casted_entity_id: None,
             error: None,
         }
     }
 
     pub fn build_schema_graph(&mut self, gts_id: &str) -> Value {
+        self.build_schema_graph_with_depth(gts_id, usize::MAX)
+    }
+
+    /// Like [`GtsStore::build_schema_graph`], but nodes deeper than `max_depth` (the root
+    /// is at depth 0) are replaced with a `{"id": ..., "truncated": true}` marker.
+    pub fn build_schema_graph_with_depth(&mut self, gts_id: &str, max_depth: usize) -> Value {
         let mut seen_gts_ids = std::collections::HashSet::new();
-        self.gts2node(gts_id, &mut seen_gts_ids)
+        self.gts2node(gts_id, 0, max_depth, &mut seen_gts_ids)
     }
 
+    /// Builds the graph node for `gts_id`. Every node carries `id`, `is_schema`,
+    /// `depth` (distance from the root), `ref_count` (number of entries in `refs`) and
+    /// `instance_count` (registered instances whose schema is this node).
     fn gts2node(
         &mut self,
         gts_id: &str,
+        depth: usize,
+        max_depth: usize,
         seen_gts_ids: &mut std::collections::HashSet<String>,
     ) -> Value {
         let mut ret = serde_json::Map::new();
         ret.insert("id".to_owned(), Value::String(gts_id.to_owned()));
+        if depth > max_depth {
+            ret.insert("truncated".to_owned(), Value::Bool(true));
+            return Value::Object(ret);
+        }
+        let is_schema = self
+            .by_id
+            .get(gts_id)
+            .map_or_else(|| gts_id.ends_with('~'), |entity| entity.is_schema);
+        ret.insert("is_schema".to_owned(), Value::Bool(is_schema));
+        ret.insert("depth".to_owned(), Value::from(depth));
+        ret.insert("ref_count".to_owned(), Value::from(0));
+        ret.insert(
+            "instance_count".to_owned(),
+            Value::from(self.count_instances_of(gts_id)),
+        );
 
         if seen_gts_ids.contains(gts_id) {
             return Value::Object(ret);
@@ -828,9 +1456,13 @@ impl GtsStore {
                 .collect();
 
             for (source_path, ref_id) in ref_ids {
-                refs.insert(source_path, self.gts2node(&ref_id, seen_gts_ids));
+                refs.insert(
+                    source_path,
+                    self.gts2node(&ref_id, depth + 1, max_depth, seen_gts_ids),
+                );
             }
 
+            ret.insert("ref_count".to_owned(), Value::from(refs.len()));
             if !refs.is_empty() {
                 ret.insert("refs".to_owned(), Value::Object(refs));
             }
@@ -842,7 +1474,7 @@ impl GtsStore {
                     let schema_id_clone = schema_id.clone();
                     ret.insert(
                         "schema_id".to_owned(),
-                        self.gts2node(&schema_id_clone, seen_gts_ids),
+                        self.gts2node(&schema_id_clone, depth + 1, max_depth, seen_gts_ids),
                     );
                 }
             } else {
@@ -869,6 +1501,110 @@ impl GtsStore {
 
     #[must_use]
     pub fn query(&self, expr: &str, limit: usize) -> GtsStoreQueryResult {
+        self.query_with_options(
+            expr,
+            &QueryOptions {
+                limit,
+                ..QueryOptions::default()
+            },
+        )
+    }
+
+    /// Runs a query with pagination and sorting.
+    ///
+    /// Matches are ordered by the `sort_by` content field (entities missing the
+    /// field come last), with ties and the unsorted case ordered by entity ID,
+    /// so results are stable across calls.
+    #[must_use]
+    pub fn query_with_options(&self, expr: &str, options: &QueryOptions) -> GtsStoreQueryResult {
+        self.query_entities(expr, options, |_| true)
+    }
+
+    /// Like [`GtsStore::query`], but only matches schemas.
+    #[must_use]
+    pub fn query_schemas(&self, pattern: &str, limit: usize) -> GtsStoreQueryResult {
+        let options = QueryOptions {
+            limit,
+            ..QueryOptions::default()
+        };
+        self.query_entities(pattern, &options, |entity| entity.is_schema)
+    }
+
+    /// Like [`GtsStore::query`], but only matches instances.
+    #[must_use]
+    pub fn query_instances(&self, pattern: &str, limit: usize) -> GtsStoreQueryResult {
+        let options = QueryOptions {
+            limit,
+            ..QueryOptions::default()
+        };
+        self.query_entities(pattern, &options, |entity| !entity.is_schema)
+    }
+
+    /// Case-insensitive substring search across the string values of every entity's content.
+    ///
+    /// Nested objects and arrays are searched recursively. When `fields` is not empty,
+    /// only values stored under one of those field names (at any depth) are considered.
+    /// Results are sorted by entity ID.
+    #[must_use]
+    pub fn search(&self, query: &str, fields: &[&str], limit: usize) -> GtsStoreQueryResult {
+        let _span =
+            tracing::info_span!("gts.store.search", operation = "search", query = %query, limit)
+                .entered();
+        let needle = query.to_lowercase();
+        let mut matches: Vec<(&String, &GtsEntity)> = self
+            .by_id
+            .iter()
+            .filter(|(_, entity)| {
+                Self::content_contains(&entity.content, &needle, fields, fields.is_empty())
+            })
+            .collect();
+        matches.sort_by(|a, b| a.0.cmp(b.0));
+
+        let results: Vec<Value> = matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, entity)| entity.content.clone())
+            .collect();
+        GtsStoreQueryResult {
+            error: String::new(),
+            count: results.len(),
+            limit,
+            results,
+        }
+    }
+
+    /// Whether any string in `value` contains the lowercase `needle`; `in_field` tells
+    /// whether `value` sits under one of the searched `fields`.
+    fn content_contains(value: &Value, needle: &str, fields: &[&str], in_field: bool) -> bool {
+        match value {
+            Value::String(s) => in_field && s.to_lowercase().contains(needle),
+            Value::Array(items) => items
+                .iter()
+                .any(|item| Self::content_contains(item, needle, fields, in_field)),
+            Value::Object(map) => map.iter().any(|(key, v)| {
+                let in_field = fields.is_empty() || fields.contains(&key.as_str());
+                Self::content_contains(v, needle, fields, in_field)
+            }),
+            _ => false,
+        }
+    }
+
+    fn query_entities(
+        &self,
+        expr: &str,
+        options: &QueryOptions,
+        include: fn(&GtsEntity) -> bool,
+    ) -> GtsStoreQueryResult {
+        let limit = options.limit;
+        let _span = tracing::info_span!(
+            "gts.store.query",
+            operation = "query",
+            expr = %expr,
+            limit,
+            offset = options.offset
+        )
+        .entered();
+
         let mut result = GtsStoreQueryResult {
             error: String::new(),
             count: 0,
@@ -897,13 +1633,15 @@ impl GtsStore {
             return result;
         }
 
-        // Filter entities
-        for entity in self.by_id.values() {
-            if result.results.len() >= limit {
-                break;
-            }
-
-            if !entity.content.is_object() {
+        // Filter entities, scanning only indexed candidates when a filter allows it
+        let candidates: Box<dyn Iterator<Item = (&String, &GtsEntity)>> =
+            match self.indexed_candidates(&filters) {
+                Some(ids) => Box::new(ids.iter().filter_map(|id| self.by_id.get_key_value(id))),
+                None => Box::new(self.by_id.iter()),
+            };
+        let mut matches: Vec<(&String, &GtsEntity)> = Vec::new();
+        for (id, entity) in candidates {
+            if !include(entity) || !entity.content.is_object() {
                 continue;
             }
 
@@ -927,13 +1665,73 @@ impl GtsStore {
                 continue;
             }
 
-            result.results.push(entity.content.clone());
+            matches.push((id, entity));
         }
 
+        Self::sort_query_matches(&mut matches, options);
+        result.results = matches
+            .into_iter()
+            .skip(options.offset)
+            .take(limit)
+            .map(|(_, entity)| entity.content.clone())
+            .collect();
+
         result.count = result.results.len();
         result
     }
 
+    /// Returns the smallest set of candidate IDs from the field index for the
+    /// exact-value filters, or `None` if no filter is on an indexed field.
+    fn indexed_candidates(&self, filters: &HashMap<String, String>) -> Option<&[String]> {
+        filters
+            .iter()
+            .filter(|(field, value)| {
+                value.as_str() != "*" && self.indexed_fields.iter().any(|f| f == *field)
+            })
+            .map(|(field, value)| {
+                self.by_field
+                    .get(&(field.clone(), value.clone()))
+                    .map_or(&[][..], Vec::as_slice)
+            })
+            .min_by_key(|ids| ids.len())
+    }
+
+    fn sort_query_matches(matches: &mut [(&String, &GtsEntity)], options: &QueryOptions) {
+        let Some(field) = options.sort_by.as_deref() else {
+            matches.sort_by(|a, b| a.0.cmp(b.0));
+            return;
+        };
+
+        matches.sort_by(|(a_id, a), (b_id, b)| {
+            let ordering = match (a.content.get(field), b.content.get(field)) {
+                (Some(x), Some(y)) => {
+                    let ordering = Self::compare_field_values(x, y);
+                    if options.sort_desc {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            ordering.then_with(|| a_id.cmp(b_id))
+        });
+    }
+
+    fn compare_field_values(a: &Value, b: &Value) -> Ordering {
+        match (a, b) {
+            (Value::Number(x), Value::Number(y)) => x
+                .as_f64()
+                .partial_cmp(&y.as_f64())
+                .unwrap_or(Ordering::Equal),
+            (Value::String(x), Value::String(y)) => x.cmp(y),
+            (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+            _ => a.to_string().cmp(&b.to_string()),
+        }
+    }
+
     fn parse_query_filters(filter_str: &str) -> HashMap<String, String> {
         let mut filters = HashMap::new();
         if filter_str.is_empty() {
@@ -1222,9 +2020,37 @@ mod tests {
     }
 
     #[test]
-    fn test_gts_store_validate_instance_missing_schema() {
+    fn test_gts_store_items_paginated() {
         let mut store = GtsStore::new(None);
-        let cfg = GtsConfig::default();
+        for i in [3, 0, 4, 1, 2] {
+            let type_id = format!("gts.vendor.package.namespace.type.v{i}.0~");
+            store
+                .register_schema(&type_id, &json!({"type": "object"}))
+                .expect("test");
+        }
+
+        let ids = |page: Vec<(&String, &GtsEntity)>| -> Vec<String> {
+            page.into_iter().map(|(id, _)| id.clone()).collect()
+        };
+        assert_eq!(
+            ids(store.items_paginated(0, 2)),
+            [
+                "gts.vendor.package.namespace.type.v0.0~",
+                "gts.vendor.package.namespace.type.v1.0~"
+            ]
+        );
+        assert_eq!(
+            ids(store.items_paginated(4, 2)),
+            ["gts.vendor.package.namespace.type.v4.0~"]
+        );
+        assert!(store.items_paginated(5, 2).is_empty());
+        assert_eq!(store.items_paginated(0, 100).len(), 5);
+    }
+
+    #[test]
+    fn test_gts_store_validate_instance_missing_schema() {
+        let mut store = GtsStore::new(None);
+        let cfg = GtsConfig::default();
 
         // Add an entity without a schema
         let content = json!({
@@ -1336,9 +2162,153 @@ mod tests {
 
         let error = StoreError::SchemaForInstanceNotFound("instance_id".to_owned());
         assert!(error.to_string().contains("instance_id"));
+
+        let error = StoreError::CircularReference(vec![
+            "gts.a.b.c.d.v1~".to_owned(),
+            "gts.a.b.c.e.v1~".to_owned(),
+        ]);
+        assert_eq!(
+            error.to_string(),
+            "Circular reference: gts.a.b.c.d.v1~ \u{2192} gts.a.b.c.e.v1~ \u{2192} gts.a.b.c.d.v1~"
+        );
     }
 
-    // Note: resolve_schema_refs is a private method, tested indirectly through validate_instance()
+    #[test]
+    fn test_resolve_all_refs_inlines_multi_level_chain() {
+        let mut store = GtsStore::new(None);
+        let schemas = [
+            (
+                "gts.x.test.resolve.base.v1~",
+                json!({
+                    "$id": "gts://gts.x.test.resolve.base.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object",
+                    "properties": {"id": {"type": "string"}}
+                }),
+            ),
+            (
+                "gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~",
+                json!({
+                    "$id": "gts://gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "allOf": [
+                        {"$ref": "gts://gts.x.test.resolve.base.v1~"},
+                        {"properties": {"mid": {"type": "integer"}}}
+                    ]
+                }),
+            ),
+            (
+                "gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~x.test.resolve.leaf.v1~",
+                json!({
+                    "$id": "gts://gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~x.test.resolve.leaf.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "allOf": [
+                        {"$ref": "gts://gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~"},
+                        {"properties": {"leaf": {"type": "boolean"}}}
+                    ]
+                }),
+            ),
+        ];
+        for (id, schema) in &schemas {
+            store.register_schema(id, schema).expect("test");
+        }
+
+        let resolved = store
+            .resolve_all_refs(
+                "gts.x.test.resolve.base.v1~x.test.resolve.mid.v1~x.test.resolve.leaf.v1~",
+            )
+            .expect("test");
+        assert!(!resolved.to_string().contains("$ref"));
+        assert!(resolved.get("$id").is_none());
+        assert!(resolved.get("$schema").is_none());
+        for field in ["id", "mid", "leaf"] {
+            assert!(resolved["properties"].get(field).is_some(), "{field}");
+        }
+
+        let validator = jsonschema::validator_for(&resolved).expect("test");
+        assert!(validator.is_valid(&json!({"id": "a", "mid": 1, "leaf": true})));
+        assert!(!validator.is_valid(&json!({"id": "a", "mid": "one"})));
+
+        assert!(matches!(
+            store.resolve_all_refs("gts.x.test.resolve.missing.v1~"),
+            Err(StoreError::SchemaNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_schema_chain() {
+        let base = "gts.x.test.chain.base.v1~";
+        let mid = "gts.x.test.chain.base.v1~x.test.chain.mid.v1~";
+        let leaf = "gts.x.test.chain.base.v1~x.test.chain.mid.v1~x.test.chain.leaf.v1~";
+        let child_of = |parent: &str| {
+            json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "allOf": [{"$ref": format!("gts://{parent}")}, {"type": "object"}]
+            })
+        };
+
+        let mut store = GtsStore::new(None);
+        store.register_schema(mid, &child_of(base)).expect("test");
+        store.register_schema(leaf, &child_of(mid)).expect("test");
+        assert!(matches!(
+            store.validate_schema_chain(leaf),
+            Err(StoreError::SchemaNotFound(ref id)) if id == base
+        ));
+
+        store
+            .register_schema(
+                base,
+                &json!({"$schema": "http://json-schema.org/draft-07/schema#", "type": "not-a-type"}),
+            )
+            .expect("test");
+        // The leaf itself is fine; only the chain check reaches the broken base
+        assert!(store.validate_schema(leaf).is_ok());
+        let err = store.validate_schema_chain(leaf).unwrap_err();
+        assert!(matches!(err, StoreError::ValidationError(_)));
+        assert!(
+            err.to_string()
+                .contains(&format!("Ancestor schema '{base}'"))
+        );
+
+        store
+            .register_schema(
+                base,
+                &json!({"$schema": "http://json-schema.org/draft-07/schema#", "type": "object"}),
+            )
+            .expect("test");
+        assert!(store.validate_schema_chain(leaf).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_all_refs_detects_cycles() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.test.cycle.a.v1~",
+                &json!({
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "properties": {"b": {"$ref": "gts://gts.x.test.cycle.b.v1~"}}
+                }),
+            )
+            .expect("test");
+        store
+            .register_schema(
+                "gts.x.test.cycle.b.v1~",
+                &json!({
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "properties": {"a": {"$ref": "gts://gts.x.test.cycle.a.v1~"}}
+                }),
+            )
+            .expect("test");
+
+        let err = store
+            .resolve_all_refs("gts.x.test.cycle.a.v1~")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Circular reference: gts.x.test.cycle.a.v1~ \u{2192} gts.x.test.cycle.b.v1~ \u{2192} gts.x.test.cycle.a.v1~"
+        );
+    }
 
     #[test]
     fn test_gts_store_cast() {
@@ -3306,6 +4276,31 @@ mod tests {
         assert_eq!(store.items().count(), 3);
     }
 
+    #[test]
+    fn test_gts_store_is_sync_with_non_sync_reader() {
+        // Counts lookups through a `Cell`, so the reader is `Send` but not `Sync`
+        struct CountingReader(std::cell::Cell<usize>);
+
+        impl GtsReader for CountingReader {
+            fn iter(&mut self) -> Box<dyn Iterator<Item = GtsEntity> + '_> {
+                Box::new(std::iter::empty())
+            }
+
+            fn read_by_id(&self, _entity_id: &str) -> Option<GtsEntity> {
+                self.0.set(self.0.get() + 1);
+                None
+            }
+
+            fn reset(&mut self) {}
+        }
+
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        let mut store = GtsStore::new(Some(Box::new(CountingReader(std::cell::Cell::new(0)))));
+        assert_sync(&store);
+        assert!(store.get("gts.x.test.sync.item.v1~").is_none());
+    }
+
     #[test]
     fn test_gts_store_get_from_reader() {
         let cfg = GtsConfig::default();
@@ -4075,4 +5070,557 @@ mod tests {
             _ => panic!("Expected SchemaForInstanceNotFound error"),
         }
     }
+
+    #[derive(Default)]
+    struct RecordingListener {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl StoreListener for RecordingListener {
+        fn on_register(&self, entity: &GtsEntity) {
+            self.events.lock().expect("test").push(format!(
+                "register:{}",
+                entity.effective_id().unwrap_or_default()
+            ));
+        }
+
+        fn on_remove(&self, id: &str) {
+            self.events
+                .lock()
+                .expect("test")
+                .push(format!("remove:{id}"));
+        }
+    }
+
+    #[test]
+    fn test_gts_store_listener_notifications() {
+        let listener = Arc::new(RecordingListener::default());
+        let mut store = GtsStore::new(None).with_listener(listener.clone());
+
+        store
+            .register_schema(
+                "gts.x.core.events.type.v1~",
+                &json!({"$schema": "http://json-schema.org/draft-07/schema#"}),
+            )
+            .expect("test");
+        let cfg = GtsConfig::default();
+        let instance = GtsEntity::new(
+            None,
+            None,
+            &json!({"id": "gts.x.core.events.type.v1~x.app.auth.login.v1"}),
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        store.register(instance).expect("test");
+
+        assert!(store.remove("gts.x.core.events.type.v1~").is_some());
+        // Removing a missing entity does not notify
+        assert!(store.remove("gts.x.core.events.missing.v1~").is_none());
+
+        assert_eq!(
+            *listener.events.lock().expect("test"),
+            vec![
+                "register:gts.x.core.events.type.v1~",
+                "register:gts.x.core.events.type.v1~x.app.auth.login.v1",
+                "remove:gts.x.core.events.type.v1~",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gts_store_register_strict_rejects_duplicates() {
+        let mut store = GtsStore::new(None);
+        let cfg = GtsConfig::default();
+        let make = |name: &str| {
+            GtsEntity::new(
+                None,
+                None,
+                &json!({"id": "gts.x.core.events.type.v1~x.app.auth.login.v1", "name": name}),
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            )
+        };
+
+        store.register_strict(make("first")).expect("test");
+        let err = store.register_strict(make("second")).unwrap_err();
+        assert!(matches!(
+            &err,
+            StoreError::DuplicateEntity(id) if id == "gts.x.core.events.type.v1~x.app.auth.login.v1"
+        ));
+        assert!(err.to_string().contains("already registered"));
+
+        // The original entity is kept
+        let entity = store
+            .get("gts.x.core.events.type.v1~x.app.auth.login.v1")
+            .expect("test");
+        assert_eq!(entity.content["name"], "first");
+    }
+
+    #[test]
+    fn test_cast_all_instances() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.test.bulk.order.v1.0~",
+                &json!({
+                    "$id": "gts://gts.x.test.bulk.order.v1.0~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object",
+                    "properties": {"id": {"type": "string"}, "name": {"type": "string"}}
+                }),
+            )
+            .expect("test");
+        store
+            .register_schema(
+                "gts.x.test.bulk.order.v1.1~",
+                &json!({
+                    "$id": "gts://gts.x.test.bulk.order.v1.1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"},
+                        "name": {"type": "string"},
+                        "status": {"type": "string", "default": "new"}
+                    }
+                }),
+            )
+            .expect("test");
+
+        let cfg = GtsConfig::default();
+        for content in [
+            json!({"id": "gts.x.test.bulk.order.v1.0~x.shop.orders.first.v1", "name": "first"}),
+            json!({
+                "id": "7a1d2f3e-0000-4000-8000-000000000001",
+                "type": "gts.x.test.bulk.order.v1.0~",
+                "name": "anonymous"
+            }),
+            json!({"id": "gts.x.test.bulk.other.v1.0~x.shop.orders.other.v1", "name": "other"}),
+        ] {
+            let entity = GtsEntity::new(
+                None,
+                None,
+                &content,
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            );
+            store.register(entity).expect("test");
+        }
+
+        let results =
+            store.cast_all_instances("gts.x.test.bulk.order.v1.0~", "gts.x.test.bulk.order.v1.1~");
+        let ids: Vec<String> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "7a1d2f3e-0000-4000-8000-000000000001".to_owned(),
+                "gts.x.test.bulk.order.v1.1~x.shop.orders.first.v1".to_owned(),
+            ]
+        );
+
+        let upgraded = store
+            .get("gts.x.test.bulk.order.v1.1~x.shop.orders.first.v1")
+            .expect("test");
+        assert_eq!(
+            upgraded.schema_id.as_deref(),
+            Some("gts.x.test.bulk.order.v1.1~")
+        );
+        assert_eq!(
+            upgraded.content["id"],
+            "gts.x.test.bulk.order.v1.1~x.shop.orders.first.v1"
+        );
+        assert_eq!(upgraded.content["status"], "new");
+
+        let anonymous = store
+            .get("7a1d2f3e-0000-4000-8000-000000000001")
+            .expect("test");
+        assert_eq!(anonymous.content["type"], "gts.x.test.bulk.order.v1.1~");
+        assert_eq!(
+            anonymous.schema_id.as_deref(),
+            Some("gts.x.test.bulk.order.v1.1~")
+        );
+
+        // The original chained instance is kept alongside the upgraded copy
+        assert!(
+            store
+                .get("gts.x.test.bulk.order.v1.0~x.shop.orders.first.v1")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_compact_removes_orphans_transitively() {
+        let mut store = GtsStore::new(None);
+        let cfg = GtsConfig::default();
+        let mut add = |content: Value| {
+            let entity = GtsEntity::new(
+                None,
+                None,
+                &content,
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            );
+            store.register(entity).expect("test");
+        };
+
+        add(json!({
+            "$id": "gts://gts.x.test.compact.base.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        }));
+        add(json!({"id": "gts.x.test.compact.base.v1~x.test.compact.kept.v1"}));
+        // Derived schema whose parent was never registered, plus one of its instances
+        add(json!({
+            "$id": "gts://gts.x.test.compact.gone.v1~x.test.compact.child.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        }));
+        add(json!({
+            "id": "gts.x.test.compact.gone.v1~x.test.compact.child.v1~x.test.compact.item.v1"
+        }));
+        // Instance of a schema that does not exist
+        add(json!({"id": "gts.x.test.compact.missing.v1~x.test.compact.lost.v1"}));
+
+        let report = store.compact();
+        assert_eq!(report.schemas_removed, 1);
+        assert_eq!(report.instances_removed, 2);
+        assert_eq!(
+            report.removed_ids,
+            vec![
+                "gts.x.test.compact.gone.v1~x.test.compact.child.v1~".to_owned(),
+                "gts.x.test.compact.gone.v1~x.test.compact.child.v1~x.test.compact.item.v1"
+                    .to_owned(),
+                "gts.x.test.compact.missing.v1~x.test.compact.lost.v1".to_owned(),
+            ]
+        );
+        assert_eq!(store.items().count(), 2);
+
+        // A second pass finds nothing left to remove
+        let report = store.compact();
+        assert!(report.removed_ids.is_empty());
+    }
+
+    #[test]
+    fn test_schema_for() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.test.lookup.event.v1~",
+                &json!({
+                    "$id": "gts://gts.x.test.lookup.event.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object"
+                }),
+            )
+            .expect("test");
+
+        let cfg = GtsConfig::default();
+        for content in [
+            json!({"id": "gts.x.test.lookup.event.v1~x.test.lookup.first.v1"}),
+            json!({"id": "9b0c4a52-0000-4000-8000-000000000001", "type": "gts.x.test.lookup.event.v1~"}),
+            json!({"id": "9b0c4a52-0000-4000-8000-000000000002", "type": "gts.x.test.lookup.gone.v1~"}),
+            json!({"id": "9b0c4a52-0000-4000-8000-000000000003"}),
+        ] {
+            let entity = GtsEntity::new(
+                None,
+                None,
+                &content,
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            );
+            store.register(entity).expect("test");
+        }
+
+        // Chained instance ID without a cfg-extracted schema_id
+        let chained = GtsEntity::new(
+            None,
+            None,
+            &json!({"name": "chained"}),
+            None,
+            Some(GtsID::new("gts.x.test.lookup.event.v1~x.test.lookup.second.v1").expect("test")),
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        store.register(chained).expect("test");
+
+        for id in [
+            "gts.x.test.lookup.event.v1~x.test.lookup.first.v1",
+            "gts.x.test.lookup.event.v1~x.test.lookup.second.v1",
+            "9b0c4a52-0000-4000-8000-000000000001",
+        ] {
+            let schema = store.schema_for(id).expect("test");
+            assert_eq!(
+                schema.gts_id.expect("test").id,
+                "gts.x.test.lookup.event.v1~"
+            );
+        }
+
+        assert!(matches!(
+            store.schema_for("9b0c4a52-0000-4000-8000-000000000002"),
+            Err(StoreError::SchemaNotFound(id)) if id == "gts.x.test.lookup.gone.v1~"
+        ));
+        assert!(matches!(
+            store.schema_for("9b0c4a52-0000-4000-8000-000000000003"),
+            Err(StoreError::SchemaForInstanceNotFound(_))
+        ));
+        assert!(matches!(
+            store.schema_for("gts.x.test.lookup.event.v1~x.test.lookup.none.v1"),
+            Err(StoreError::ObjectNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_query_result_into_typed_iter() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Item {
+            name: String,
+        }
+
+        let result = GtsStoreQueryResult {
+            error: String::new(),
+            count: 3,
+            limit: 10,
+            results: vec![
+                json!({"name": "a"}),
+                json!({"name": 1}),
+                json!({"name": "c"}),
+            ],
+        };
+
+        let items: Vec<Result<Item, serde_json::Error>> = result.into_typed_iter().collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().expect("test").name, "a");
+        assert!(items[1].is_err());
+        assert_eq!(items[2].as_ref().expect("test").name, "c");
+    }
+
+    #[test]
+    fn test_gts_store_query_with_options() {
+        let mut store = GtsStore::new(None);
+        let cfg = GtsConfig::default();
+        for (name, priority) in [
+            ("b", json!(2)),
+            ("a", json!(10)),
+            ("c", json!(1)),
+            ("d", Value::Null),
+        ] {
+            let mut content =
+                json!({"id": format!("gts.x.test.sorted.item.v1~x.test.sorted.{name}.v1")});
+            if !priority.is_null() {
+                content["priority"] = priority;
+            }
+            let entity = GtsEntity::new(
+                None,
+                None,
+                &content,
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            );
+            store.register(entity).expect("test");
+        }
+        let names = |result: &GtsStoreQueryResult| -> Vec<String> {
+            result
+                .results
+                .iter()
+                .map(|v| {
+                    v["id"]
+                        .as_str()
+                        .expect("test")
+                        .split('.')
+                        .nth(8)
+                        .expect("test")
+                        .to_owned()
+                })
+                .collect()
+        };
+
+        // Without sort_by, results are ordered by ID
+        let result = store.query("gts.x.test.sorted.*", 10);
+        assert_eq!(names(&result), vec!["a", "b", "c", "d"]);
+
+        let mut options = QueryOptions {
+            sort_by: Some("priority".to_owned()),
+            ..QueryOptions::default()
+        };
+        let result = store.query_with_options("gts.x.test.sorted.*", &options);
+        assert_eq!(names(&result), vec!["c", "b", "a", "d"]);
+
+        options.sort_desc = true;
+        let result = store.query_with_options("gts.x.test.sorted.*", &options);
+        assert_eq!(names(&result), vec!["a", "b", "c", "d"]);
+
+        options.offset = 1;
+        options.limit = 2;
+        let result = store.query_with_options("gts.x.test.sorted.*", &options);
+        assert_eq!(names(&result), vec!["b", "c"]);
+        assert_eq!(result.count, 2);
+        assert_eq!(result.limit, 2);
+    }
+
+    #[test]
+    fn test_gts_store_query_schemas_and_instances() {
+        let mut store = GtsStore::new(None);
+        store
+            .register_schema(
+                "gts.x.test.kinds.event.v1~",
+                &json!({
+                    "$id": "gts://gts.x.test.kinds.event.v1~",
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object"
+                }),
+            )
+            .expect("test");
+        let cfg = GtsConfig::default();
+        for name in ["first", "second"] {
+            let content =
+                json!({"id": format!("gts.x.test.kinds.event.v1~x.test.kinds.{name}.v1")});
+            let entity = GtsEntity::new(
+                None,
+                None,
+                &content,
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            );
+            store.register(entity).expect("test");
+        }
+
+        assert_eq!(store.query("gts.x.test.kinds.*", 10).count, 3);
+
+        let schemas = store.query_schemas("gts.x.test.kinds.*", 10);
+        assert_eq!(schemas.count, 1);
+        assert_eq!(
+            schemas.results[0]["$id"],
+            "gts://gts.x.test.kinds.event.v1~"
+        );
+
+        let instances = store.query_instances("gts.x.test.kinds.*", 10);
+        assert_eq!(instances.count, 2);
+        assert!(instances.results.iter().all(|v| v.get("$schema").is_none()));
+
+        assert_eq!(store.query_instances("gts.x.test.kinds.*", 1).count, 1);
+    }
+
+    #[test]
+    fn test_field_index_maintained_and_used_by_query() {
+        let mut store = GtsStore::new(None).with_field_index(&["status"]);
+        let cfg = GtsConfig::default();
+        let make = |name: &str, status: &str| {
+            GtsEntity::new(
+                None,
+                None,
+                &json!({
+                    "id": format!("gts.x.test.indexed.order.v1~x.test.indexed.{name}.v1"),
+                    "status": status
+                }),
+                Some(&cfg),
+                None,
+                false,
+                String::new(),
+                None,
+                None,
+            )
+        };
+        store.register(make("first", "open")).expect("test");
+        store.register(make("second", "open")).expect("test");
+        store.register(make("third", "closed")).expect("test");
+
+        let open = |store: &GtsStore| store.query("gts.x.test.indexed.*[status=open]", 10).count;
+        assert_eq!(open(&store), 2);
+        assert_eq!(
+            store.by_field[&("status".to_owned(), "closed".to_owned())],
+            vec!["gts.x.test.indexed.order.v1~x.test.indexed.third.v1".to_owned()]
+        );
+
+        // Re-registering with a new value moves the entity in the index
+        store.register(make("first", "closed")).expect("test");
+        assert_eq!(open(&store), 1);
+        assert_eq!(
+            store.query("gts.x.test.indexed.*[status=closed]", 10).count,
+            2
+        );
+
+        store.remove("gts.x.test.indexed.order.v1~x.test.indexed.second.v1");
+        assert_eq!(open(&store), 0);
+        assert!(
+            !store
+                .by_field
+                .contains_key(&("status".to_owned(), "open".to_owned()))
+        );
+
+        // Unindexed filters still work via a full scan
+        assert_eq!(
+            store
+                .query(
+                    "gts.x.test.indexed.*[id=gts.x.test.indexed.order.v1~x.test.indexed.third.v1]",
+                    10
+                )
+                .count,
+            1
+        );
+    }
+
+    #[test]
+    fn test_rebuild_indexes_after_direct_changes() {
+        let mut store = GtsStore::new(None).with_field_index(&["status"]);
+        let cfg = GtsConfig::default();
+        let entity = GtsEntity::new(
+            None,
+            None,
+            &json!({"id": "gts.x.test.rebuild.order.v1~x.test.rebuild.first.v1", "status": "open"}),
+            Some(&cfg),
+            None,
+            false,
+            String::new(),
+            None,
+            None,
+        );
+        // Bypass `register` so the index goes stale
+        store.by_id.insert(
+            "gts.x.test.rebuild.order.v1~x.test.rebuild.first.v1".to_owned(),
+            entity,
+        );
+        assert_eq!(
+            store.query("gts.x.test.rebuild.*[status=open]", 10).count,
+            0
+        );
+
+        store.rebuild_indexes();
+        assert_eq!(
+            store.query("gts.x.test.rebuild.*[status=open]", 10).count,
+            1
+        );
+
+        store.by_id.clear();
+        store.rebuild_indexes();
+        assert!(store.by_field.is_empty());
+    }
 }
@@ -0,0 +1,95 @@
+//! WebAssembly bindings for [`GtsOps`], enabled with the `gts-wasm` feature.
+//!
+//! IDs are passed as JS strings and results are returned as plain JS objects
+//! (via `serde-wasm-bindgen`) with the same shape as the CLI's JSON output.
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::ops::GtsOps;
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// In-memory GTS operations exposed to JavaScript as `GtsOps`.
+#[wasm_bindgen(js_name = GtsOps)]
+pub struct WasmGtsOps {
+    ops: GtsOps,
+}
+
+#[wasm_bindgen(js_class = GtsOps)]
+impl WasmGtsOps {
+    /// Create an empty store; entities are added with `addEntity`.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new() -> Self {
+        WasmGtsOps {
+            ops: GtsOps::new(None, None, 0),
+        }
+    }
+
+    /// Validate a GTS ID. Returns `{ id, valid, error?, is_schema?, is_wildcard }`.
+    ///
+    /// # Errors
+    /// Returns a `JsError` if the result cannot be converted to a JS object.
+    #[wasm_bindgen(js_name = validateId)]
+    pub fn validate_id(id: &str) -> Result<JsValue, JsError> {
+        to_js(&GtsOps::validate_id(id))
+    }
+
+    /// Parse a GTS ID into its segments.
+    ///
+    /// # Errors
+    /// Returns a `JsError` if the result cannot be converted to a JS object.
+    #[wasm_bindgen(js_name = parseId)]
+    pub fn parse_id(id: &str) -> Result<JsValue, JsError> {
+        to_js(&GtsOps::parse_id(id))
+    }
+
+    /// Add a schema or instance (a JS object) to the store.
+    ///
+    /// # Errors
+    /// Returns a `JsError` if `content` is not valid JSON or the entity is rejected.
+    #[wasm_bindgen(js_name = addEntity)]
+    pub fn add_entity(&mut self, content: JsValue, validate: bool) -> Result<JsValue, JsError> {
+        let content: serde_json::Value =
+            serde_wasm_bindgen::from_value(content).map_err(|e| JsError::new(&e.to_string()))?;
+        let result = self.ops.add_entity(&content, validate);
+        if !result.ok {
+            return Err(JsError::new(&result.error));
+        }
+        to_js(&result)
+    }
+
+    /// Validate an instance against its schema.
+    ///
+    /// # Errors
+    /// Returns a `JsError` with the validation message if the instance is invalid.
+    #[wasm_bindgen(js_name = validateInstance)]
+    pub fn validate_instance(&mut self, id: &str) -> Result<JsValue, JsError> {
+        let result = self.ops.validate_instance(id);
+        if !result.ok {
+            return Err(JsError::new(&result.error));
+        }
+        to_js(&result)
+    }
+
+    /// Query the store with a GTS query expression.
+    ///
+    /// # Errors
+    /// Returns a `JsError` if the query expression is invalid.
+    pub fn query(&self, expr: &str, limit: usize) -> Result<JsValue, JsError> {
+        let result = self.ops.query(expr, limit);
+        if !result.error.is_empty() {
+            return Err(JsError::new(&result.error));
+        }
+        to_js(&result)
+    }
+}
+
+impl Default for WasmGtsOps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -92,7 +92,7 @@
 use serde_json::Value;
 use std::fmt;
 
-use crate::gts::GtsID;
+use crate::gts::{GtsID, strip_gts_uri};
 
 /// Error type for x-gts-ref validation failures
 #[derive(Debug, Clone)]
@@ -162,6 +162,63 @@ impl XGtsRefValidator {
         errors
     }
 
+    /// Collect every instance string value constrained by an `x-gts-ref`
+    ///
+    /// Walks the instance the same way as [`XGtsRefValidator::validate_instance`] and
+    /// returns `(field_path, value)` pairs, regardless of whether the value matches its pattern.
+    #[must_use]
+    pub fn referenced_ids(
+        &self,
+        instance: &Value,
+        schema: &Value,
+        instance_path: &str,
+    ) -> Vec<(String, String)> {
+        let mut refs = Vec::new();
+        Self::collect_refs(instance, schema, instance_path, &mut refs);
+        refs
+    }
+
+    fn collect_refs(inst: &Value, sch: &Value, path: &str, refs: &mut Vec<(String, String)>) {
+        let Some(sch_obj) = sch.as_object() else {
+            return;
+        };
+
+        if sch_obj.get("x-gts-ref").is_some_and(Value::is_string)
+            && let Some(inst_str) = inst.as_str()
+        {
+            refs.push((path.to_owned(), inst_str.to_owned()));
+        }
+
+        match sch_obj.get("type").and_then(Value::as_str) {
+            Some("object") => {
+                if let Some(properties) = sch_obj.get("properties").and_then(Value::as_object)
+                    && let Some(inst_obj) = inst.as_object()
+                {
+                    for (prop_name, prop_schema) in properties {
+                        if let Some(prop_value) = inst_obj.get(prop_name) {
+                            let prop_path = if path.is_empty() {
+                                prop_name.clone()
+                            } else {
+                                format!("{path}.{prop_name}")
+                            };
+                            Self::collect_refs(prop_value, prop_schema, &prop_path, refs);
+                        }
+                    }
+                }
+            }
+            Some("array") => {
+                if let Some(items) = sch_obj.get("items")
+                    && let Some(inst_arr) = inst.as_array()
+                {
+                    for (idx, item) in inst_arr.iter().enumerate() {
+                        Self::collect_refs(item, items, &format!("{path}[{idx}]"), refs);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn visit_instance(
         &self,
         inst: &Value,
@@ -517,7 +574,7 @@ impl XGtsRefValidator {
     /// contains a full GTS URI (e.g., `gts://gts.x.example._.user.v1~`) but the
     /// instance value should match without the prefix (e.g., `gts.x.example._.user.v1~`).
     fn strip_gts_uri_prefix(value: &str) -> String {
-        value.strip_prefix("gts://").unwrap_or(value).to_owned()
+        strip_gts_uri(value).unwrap_or(value).to_owned()
     }
 }
 
@@ -599,6 +656,40 @@ mod tests {
         assert!(errors.is_empty());
     }
 
+    #[test]
+    fn test_referenced_ids() {
+        let validator = XGtsRefValidator::new();
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "owner": {"type": "string", "x-gts-ref": "gts.x.test._.user.v1~"},
+                "name": {"type": "string"},
+                "tags": {
+                    "type": "array",
+                    "items": {"type": "string", "x-gts-ref": "gts.*"}
+                }
+            }
+        });
+        let instance = json!({
+            "owner": "gts.x.test._.user.v1~x.test._.alice.v1",
+            "name": "not a ref",
+            "tags": ["gts.x.test._.tag.v1~", "gts.x.test._.other.v1~"]
+        });
+
+        let refs = validator.referenced_ids(&instance, &schema, "");
+        assert_eq!(
+            refs,
+            vec![
+                (
+                    "owner".to_owned(),
+                    "gts.x.test._.user.v1~x.test._.alice.v1".to_owned()
+                ),
+                ("tags[0]".to_owned(), "gts.x.test._.tag.v1~".to_owned()),
+                ("tags[1]".to_owned(), "gts.x.test._.other.v1~".to_owned()),
+            ]
+        );
+    }
+
     #[test]
     fn test_validate_instance_with_x_gts_ref_mismatch() {
         let validator = XGtsRefValidator::new();